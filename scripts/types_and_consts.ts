@@ -22,6 +22,7 @@ export const E21 = E9.mul(E12);
 
 export const MINTER = 4_254_773_782;
 export const BURNER = 1_711_057_910;
+export const STAKE_ADMIN = 2_113_667_107;
 
 export type ProposalId = Hash;
 export type RulesId = number;
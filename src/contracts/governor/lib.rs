@@ -6,8 +6,10 @@ pub mod governor {
     use abax_governance::contracts_impls::{
         govern::{
             impls::storage::{
+                GovernDelegationStorage,
                 GovernRewardableSlashableStorage,
                 GovernStorage,
+                GovernTreasuryStorage,
             },
             traits::{
                 EmitGovernEvents,
@@ -20,6 +22,7 @@ pub mod governor {
         },
         stake::{
             impls::storage::data::{
+                StakeCheckpointsStorage,
                 StakeCounterStorage,
                 StakeStorage,
                 StakeTimesStorage,
@@ -30,17 +33,27 @@ pub mod governor {
             impls::TimestampMockStorage,
             traits::*,
         },
+        vesting::{
+            impls::VestingStorage,
+            traits::*,
+        },
     };
 
     // imports from ink!
-    use ink::codegen::{
-        EmitEvent,
-        Env,
+    use ink::{
+        codegen::{
+            EmitEvent,
+            Env,
+        },
+        prelude::vec::Vec,
     };
 
     // imports from openbrush
     use openbrush::{
-        contracts::ownable::*,
+        contracts::{
+            access_control::*,
+            ownable::*,
+        },
         traits::{
             Storage,
             String,
@@ -53,48 +66,94 @@ pub mod governor {
         #[storage_field]
         ownable: ownable::Data,
         #[storage_field]
+        access: access_control::Data,
+        #[storage_field]
         stake: StakeStorage,
         #[storage_field]
         stake_times: StakeTimesStorage,
         #[storage_field]
         stake_counter: StakeCounterStorage,
         #[storage_field]
+        stake_checkpoints: StakeCheckpointsStorage,
+        #[storage_field]
         timestamp: TimestampMockStorage,
         #[storage_field]
         gov: GovernStorage,
         #[storage_field]
         gov_reward: GovernRewardableSlashableStorage,
+        #[storage_field]
+        gov_delegation: GovernDelegationStorage,
+        #[storage_field]
+        gov_treasury: GovernTreasuryStorage,
+        #[storage_field]
+        vesting: VestingStorage,
     }
 
     // Section contains default implementation without any modifications
     impl Ownable for Governor {}
+    impl AccessControl for Governor {}
     impl Stake for Governor {}
     impl StakeView for Governor {}
     impl StakeCounter for Governor {}
     impl StakeTimes for Governor {}
+    impl StakeCheckpoints for Governor {}
     impl StakeManage for Governor {}
     impl GovernView for Governor {}
     impl Govern for Governor {}
     impl GovernManage for Governor {}
     impl GovernRewardableSlashable for Governor {}
+    impl GovernDelegate for Governor {}
+    impl GovernTreasury for Governor {}
     impl TimestampMock for Governor {}
+    impl VestingView for Governor {}
+    impl Vesting for Governor {}
+
+    // stake changes have to be reflected in the delegated voting power
+    impl StakeCheckpointsInternal for Governor {
+        fn _checkpoint_stake_of(&mut self, account: &AccountId) -> Result<(), StakeError> {
+            self._checkpoint_stake_and_delegated_power_of(account)
+        }
+    }
+
+    // vestings are funded from and refunded to the `want` held by the treasury
+    impl VestingFunding for Governor {
+        fn _fund_vesting(&mut self, amount: &Balance) -> Result<(), VestingError> {
+            self._take_from_treasury(&TreasuryAsset::Want, amount).map_err(|err| {
+                match err {
+                    GovernError::InsufficientTreasuryBalance => VestingError::InsufficientFunds,
+                    err => err.into(),
+                }
+            })
+        }
+
+        fn _refund_vesting(&mut self, amount: &Balance) -> Result<(), VestingError> {
+            Ok(self._deposit_to_treasury(&TreasuryAsset::Want, amount)?)
+        }
+    }
 
     impl Governor {
+        /// # Panics
+        /// Panics if `unstake_period` is smaller than `minimal_unstake_period`, `maximal_number_of_unstakes` is 0
+        /// or greater than `MAXIMAL_NUMBER_OF_UNSTAKES_LIMIT`, or `rules` are invalid.
         #[ink(constructor)]
         pub fn new(
             want: AccountId,
+            minimal_unstake_period: Timestamp,
             unstake_period: Timestamp,
             maximal_number_of_unstakes: u64,
             rules: ProposalRules,
         ) -> Self {
             let mut _instance = Self::default();
             _instance.ownable._init_with_owner(_instance.env().account_id());
+            // stake parameters can only be changed through proposals
+            _instance.access._init_with_admin(_instance.env().account_id());
+            _instance.access._setup_role(STAKE_ADMIN, _instance.env().account_id());
 
             _instance.stake.want = want;
-            _instance.stake.change_unstake_period(&unstake_period);
             _instance
                 .stake
-                .change_maximal_number_of_unstakes(&maximal_number_of_unstakes);
+                .init_parameters(&minimal_unstake_period, &unstake_period, &maximal_number_of_unstakes)
+                .expect("stake parameters");
             _instance.gov.add_new_rule(&rules).expect("add_new_rule");
             _instance.gov.allow_rules(&0, &true).expect("allow_rule");
             _instance
@@ -120,6 +179,73 @@ pub mod governor {
         }
     }
 
+    // test-only messages compiled into the `governor_mock` contract to reproduce states that can't be reached otherwise
+    #[cfg(feature = "mock")]
+    impl Governor {
+        /// Sets the stake of `account` without writing a checkpoint, as if it was made before checkpointing was introduced.
+        #[ink(message)]
+        pub fn mock_set_legacy_stake(&mut self, account: AccountId, amount: Balance) {
+            let old_stake = self.stake.stake_of(&account);
+            self.stake.stakes.insert(&account, &amount);
+            self.stake.total_stake = self.stake.total_stake - old_stake + amount;
+        }
+
+        /// Sets the unstakes of `account` in the format used before the unstakes queue was introduced.
+        #[ink(message)]
+        pub fn mock_set_legacy_unstakes(&mut self, account: AccountId, unstakes: Vec<Unstake>) {
+            let old_amount: Balance = self
+                .stake
+                .initialized_unstakes_of(&account)
+                .iter()
+                .map(|u| u.amount)
+                .sum();
+            let amount: Balance = unstakes.iter().map(|u| u.amount).sum();
+            self.stake.unstakes.insert(&account, &unstakes);
+            self.stake.total_unstake = self.stake.total_unstake - old_amount + amount;
+        }
+
+        /// Moves the empty unstakes queue of `account` to start at `id`, so that the following unstakes wrap around `UnstakeId::MAX`.
+        #[ink(message)]
+        pub fn mock_set_unstakes_queue_start(&mut self, account: AccountId, id: UnstakeId) {
+            assert_eq!(self.stake.number_of_unstakes_of(&account), 0, "queue not empty");
+            self.stake.unstakes_id.insert(&account, &(id, id));
+        }
+
+        /// Sets the error returned by the `_before_propose` and `_before_execute` hooks. None makes the hooks pass.
+        #[ink(message)]
+        pub fn mock_set_hooks_error(&mut self, error: Option<GovernError>) {
+            ink::env::set_contract_storage(&MOCK_HOOKS_ERROR_KEY, &error);
+        }
+
+        fn mock_hooks_result(&self) -> Result<(), GovernError> {
+            match ink::env::get_contract_storage::<u32, Option<GovernError>>(&MOCK_HOOKS_ERROR_KEY) {
+                Ok(Some(Some(error))) => Err(error),
+                _ => Ok(()),
+            }
+        }
+    }
+
+    // kept outside of `Governor` fields so that the storage layout of the mock is the same as of the governor
+    #[cfg(feature = "mock")]
+    const MOCK_HOOKS_ERROR_KEY: u32 = ink::selector_id!("mock_hooks_error");
+
+    // hooks that fail on demand to check that their errors are surfaced by `propose` and `execute`
+    #[cfg(feature = "mock")]
+    impl GovernHooks for Governor {
+        fn _before_propose(
+            &mut self,
+            _proposer: &AccountId,
+            _proposal_id: &ProposalId,
+            _proposal: &Proposal,
+        ) -> Result<(), GovernError> {
+            self.mock_hooks_result()
+        }
+
+        fn _before_execute(&mut self, _proposal_id: &ProposalId, _proposal: &Proposal) -> Result<(), GovernError> {
+            self.mock_hooks_result()
+        }
+    }
+
     #[ink(event)]
     pub struct OwnershipTransferred {
         #[ink(topic)]
@@ -152,6 +278,18 @@ pub mod governor {
     pub struct Unstaked {
         #[ink(topic)]
         caller: AccountId,
+        #[ink(topic)]
+        recipient: AccountId,
+        amount: Balance,
+        unstakes: Vec<Unstake>,
+        remaining_unstakes: u32,
+    }
+
+    #[ink(event)]
+    pub struct UnstakeCancelled {
+        #[ink(topic)]
+        caller: AccountId,
+        amount: Balance,
     }
 
     #[ink(event)]
@@ -199,8 +337,33 @@ pub mod governor {
             )
         }
 
-        fn _emit_unstake_event(&self, caller: &AccountId) {
-            EmitEvent::<Governor>::emit_event(self.env(), Unstaked { caller: *caller })
+        fn _emit_unstake_event(
+            &self,
+            caller: &AccountId,
+            recipient: &AccountId,
+            amount: &Balance,
+            unstakes: &Vec<Unstake>,
+            remaining_unstakes: &u32,
+        ) {
+            EmitEvent::<Governor>::emit_event(
+                self.env(),
+                Unstaked {
+                    caller: *caller,
+                    recipient: *recipient,
+                    amount: *amount,
+                    unstakes: unstakes.clone(),
+                    remaining_unstakes: *remaining_unstakes,
+                },
+            )
+        }
+        fn _emit_unstake_cancelled_event(&self, caller: &AccountId, amount: &Balance) {
+            EmitEvent::<Governor>::emit_event(
+                self.env(),
+                UnstakeCancelled {
+                    caller: *caller,
+                    amount: *amount,
+                },
+            );
         }
         fn _emit_rewarded_event(&self, account: &AccountId, amount: &Balance) {
             EmitEvent::<Governor>::emit_event(
@@ -255,10 +418,26 @@ pub mod governor {
         status: ProposalStatus,
     }
 
+    #[ink(event)]
+    pub struct ProposalQueued {
+        #[ink(topic)]
+        proposal_id: ProposalId,
+        eta: Timestamp,
+    }
+
     #[ink(event)]
     pub struct ProposalExecuted {
         #[ink(topic)]
         proposal_id: ProposalId,
+        outcomes: Vec<TransactionOutcome>,
+    }
+
+    #[ink(event)]
+    pub struct ProposalCancelled {
+        #[ink(topic)]
+        proposal_id: ProposalId,
+        #[ink(topic)]
+        caller: AccountId,
     }
 
     #[ink(event)]
@@ -268,6 +447,7 @@ pub mod governor {
         #[ink(topic)]
         proposal_id: ProposalId,
         vote: Vote,
+        reason: Vec<u8>,
     }
 
     #[ink(event)]
@@ -293,6 +473,14 @@ pub mod governor {
         rules: ProposalRules,
     }
 
+    #[ink(event)]
+    pub struct ProposalRulesUpdated {
+        #[ink(topic)]
+        rules_id: RulesId,
+        version: u32,
+        rules: ProposalRules,
+    }
+
     #[ink(event)]
     pub struct RulesAllowed {
         #[ink(topic)]
@@ -300,6 +488,36 @@ pub mod governor {
         allowed: bool,
     }
 
+    #[ink(event)]
+    pub struct Delegated {
+        #[ink(topic)]
+        delegator: AccountId,
+        #[ink(topic)]
+        delegate: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct Undelegated {
+        #[ink(topic)]
+        delegator: AccountId,
+        #[ink(topic)]
+        delegate: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct TreasuryDeposited {
+        asset: TreasuryAsset,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct TreasuryWithdrawn {
+        asset: TreasuryAsset,
+        #[ink(topic)]
+        to: AccountId,
+        amount: Balance,
+    }
+
     impl EmitGovernEvents for Governor {
         fn _emit_proposal_created_event(&self, proposal_id: &ProposalId, proposal: &Proposal, description: &String) {
             EmitEvent::<Governor>::emit_event(
@@ -320,22 +538,49 @@ pub mod governor {
                 },
             )
         }
-        fn _emit_proposal_executed_event(&self, proposal_id: &ProposalId) {
+        fn _emit_proposal_queued_event(&self, proposal_id: &ProposalId, eta: &Timestamp) {
+            EmitEvent::<Governor>::emit_event(
+                self.env(),
+                ProposalQueued {
+                    proposal_id: *proposal_id,
+                    eta: *eta,
+                },
+            )
+        }
+        fn _emit_proposal_executed_event(&self, proposal_id: &ProposalId, outcomes: &Vec<TransactionOutcome>) {
             EmitEvent::<Governor>::emit_event(
                 self.env(),
                 ProposalExecuted {
                     proposal_id: *proposal_id,
+                    outcomes: outcomes.clone(),
                 },
             )
         }
 
-        fn _emit_vote_casted_event(&self, account: &AccountId, proposal_id: &ProposalId, vote: &Vote) {
+        fn _emit_proposal_cancelled_event(&self, proposal_id: &ProposalId, caller: &AccountId) {
+            EmitEvent::<Governor>::emit_event(
+                self.env(),
+                ProposalCancelled {
+                    proposal_id: *proposal_id,
+                    caller: *caller,
+                },
+            )
+        }
+
+        fn _emit_vote_casted_event(
+            &self,
+            account: &AccountId,
+            proposal_id: &ProposalId,
+            vote: &Vote,
+            reason: &Vec<u8>,
+        ) {
             EmitEvent::<Governor>::emit_event(
                 self.env(),
                 VoteCasted {
                     account: *account,
                     proposal_id: *proposal_id,
                     vote: *vote,
+                    reason: reason.clone(),
                 },
             )
         }
@@ -370,6 +615,17 @@ pub mod governor {
             )
         }
 
+        fn _emit_proposal_rules_updated_event(&self, rules_id: &RulesId, version: &u32, rules: &ProposalRules) {
+            EmitEvent::<Governor>::emit_event(
+                self.env(),
+                ProposalRulesUpdated {
+                    rules_id: *rules_id,
+                    version: *version,
+                    rules: *rules,
+                },
+            )
+        }
+
         fn _emit_rules_allowed_event(&self, rules_id: &RulesId, allowed: &bool) {
             EmitEvent::<Governor>::emit_event(
                 self.env(),
@@ -379,5 +635,102 @@ pub mod governor {
                 },
             )
         }
+
+        fn _emit_delegated_event(&self, delegator: &AccountId, delegate: &AccountId) {
+            EmitEvent::<Governor>::emit_event(
+                self.env(),
+                Delegated {
+                    delegator: *delegator,
+                    delegate: *delegate,
+                },
+            )
+        }
+
+        fn _emit_undelegated_event(&self, delegator: &AccountId, delegate: &AccountId) {
+            EmitEvent::<Governor>::emit_event(
+                self.env(),
+                Undelegated {
+                    delegator: *delegator,
+                    delegate: *delegate,
+                },
+            )
+        }
+
+        fn _emit_treasury_deposited_event(&self, asset: &TreasuryAsset, amount: &Balance) {
+            EmitEvent::<Governor>::emit_event(
+                self.env(),
+                TreasuryDeposited {
+                    asset: *asset,
+                    amount: *amount,
+                },
+            )
+        }
+
+        fn _emit_treasury_withdrawn_event(&self, asset: &TreasuryAsset, to: &AccountId, amount: &Balance) {
+            EmitEvent::<Governor>::emit_event(
+                self.env(),
+                TreasuryWithdrawn {
+                    asset: *asset,
+                    to: *to,
+                    amount: *amount,
+                },
+            )
+        }
+    }
+
+    #[ink(event)]
+    pub struct VestingCreated {
+        #[ink(topic)]
+        vesting_id: VestingId,
+        #[ink(topic)]
+        beneficiary: AccountId,
+        schedule: VestingSchedule,
+    }
+
+    #[ink(event)]
+    pub struct VestingReleased {
+        #[ink(topic)]
+        vesting_id: VestingId,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct VestingRevoked {
+        #[ink(topic)]
+        vesting_id: VestingId,
+        refunded: Balance,
+    }
+
+    impl EmitVestingEvents for Governor {
+        fn _emit_vesting_created_event(&self, vesting_id: &VestingId, schedule: &VestingSchedule) {
+            EmitEvent::<Governor>::emit_event(
+                self.env(),
+                VestingCreated {
+                    vesting_id: *vesting_id,
+                    beneficiary: schedule.beneficiary,
+                    schedule: *schedule,
+                },
+            )
+        }
+
+        fn _emit_vesting_released_event(&self, vesting_id: &VestingId, amount: &Balance) {
+            EmitEvent::<Governor>::emit_event(
+                self.env(),
+                VestingReleased {
+                    vesting_id: *vesting_id,
+                    amount: *amount,
+                },
+            )
+        }
+
+        fn _emit_vesting_revoked_event(&self, vesting_id: &VestingId, refunded: &Balance) {
+            EmitEvent::<Governor>::emit_event(
+                self.env(),
+                VestingRevoked {
+                    vesting_id: *vesting_id,
+                    refunded: *refunded,
+                },
+            )
+        }
     }
 }
@@ -1,6 +1,6 @@
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 #![feature(min_specialization)]
-#[openbrush::implementation(Ownable)]
+#[openbrush::implementation(Ownable, AccessControl)]
 #[ink::contract]
 pub mod staker {
 
@@ -8,10 +8,12 @@ pub mod staker {
         stake::{
             impls::{
                 storage::data::{
+                    StakeCheckpointsStorage,
                     StakeCounterStorage,
                     StakeStorage,
                     StakeTimesStorage,
                 },
+                StakeCheckpointsImpl,
                 StakeCounterImpl,
                 StakeImpl,
                 StakeManageImpl,
@@ -37,7 +39,10 @@ pub mod staker {
     };
     // imports from openbrush
     use openbrush::{
-        contracts::ownable::*,
+        contracts::{
+            access_control::*,
+            ownable::*,
+        },
         traits::Storage,
     };
 
@@ -47,12 +52,16 @@ pub mod staker {
         #[storage_field]
         ownable: ownable::Data,
         #[storage_field]
+        access: access_control::Data,
+        #[storage_field]
         stake: StakeStorage,
         #[storage_field]
         stake_times: StakeTimesStorage,
         #[storage_field]
         stake_counter: StakeCounterStorage,
         #[storage_field]
+        stake_checkpoints: StakeCheckpointsStorage,
+        #[storage_field]
         timestamp: TimestampMockStorage,
     }
 
@@ -73,6 +82,21 @@ pub mod staker {
         fn unstake(&mut self) -> Result<Balance, StakeError> {
             StakeImpl::unstake(self)
         }
+
+        #[ink(message)]
+        fn unstake_to(&mut self, recipient: AccountId, max_amount: Balance) -> Result<Balance, StakeError> {
+            StakeImpl::unstake_to(self, recipient, max_amount)
+        }
+
+        #[ink(message)]
+        fn cancel_unstake(&mut self, index: u32) -> Result<Balance, StakeError> {
+            StakeImpl::cancel_unstake(self, index)
+        }
+
+        #[ink(message)]
+        fn restake_all_unstakes(&mut self) -> Result<Balance, StakeError> {
+            StakeImpl::restake_all_unstakes(self)
+        }
     }
     impl StakeViewImpl for Staker {}
     impl StakeView for Staker {
@@ -86,6 +110,11 @@ pub mod staker {
             StakeViewImpl::unstake_period(self)
         }
 
+        #[ink(message)]
+        fn minimal_unstake_period(&self) -> Timestamp {
+            StakeViewImpl::minimal_unstake_period(self)
+        }
+
         #[ink(message)]
         fn maximal_number_of_unstakes(&self) -> u64 {
             StakeViewImpl::maximal_number_of_unstakes(self)
@@ -141,6 +170,14 @@ pub mod staker {
         }
     }
 
+    impl StakeCheckpointsImpl for Staker {}
+    impl StakeCheckpoints for Staker {
+        #[ink(message)]
+        fn stake_at(&self, account: AccountId, timestamp: Timestamp) -> Balance {
+            StakeCheckpointsImpl::stake_at(self, account, timestamp)
+        }
+    }
+
     impl StakeCounterImpl for Staker {}
     impl StakeCounter for Staker {
         #[ink(message)]
@@ -184,14 +221,31 @@ pub mod staker {
     }
 
     impl Staker {
+        /// # Panics
+        /// Panics if `unstake_period` is smaller than `minimal_unstake_period`
+        /// or `maximal_number_of_initialized_unstakes` is 0 or greater than `MAXIMAL_NUMBER_OF_UNSTAKES_LIMIT`.
         #[ink(constructor)]
-        pub fn new(want: AccountId, unstake_period: Timestamp, maximal_number_of_initialized_unstakes: u64) -> Self {
+        pub fn new(
+            want: AccountId,
+            minimal_unstake_period: Timestamp,
+            unstake_period: Timestamp,
+            maximal_number_of_initialized_unstakes: u64,
+        ) -> Self {
             let mut _instance = Self::default();
             // _instance.ownable._init_with_owner(_instance.env().account_id());
+            let caller = _instance.env().caller();
+            access_control::Internal::_init_with_admin(&mut _instance, Some(caller));
+            access_control::Internal::_setup_role(&mut _instance, STAKE_ADMIN, Some(caller));
 
             _instance.stake.want = want;
-            _instance.stake.unstake_period = unstake_period;
-            _instance.stake.maximal_number_of_unstakes = maximal_number_of_initialized_unstakes;
+            _instance
+                .stake
+                .init_parameters(
+                    &minimal_unstake_period,
+                    &unstake_period,
+                    &maximal_number_of_initialized_unstakes,
+                )
+                .expect("stake parameters");
             _instance
         }
     }
@@ -228,6 +282,18 @@ pub mod staker {
     pub struct Unstaked {
         #[ink(topic)]
         caller: AccountId,
+        #[ink(topic)]
+        recipient: AccountId,
+        amount: Balance,
+        unstakes: Vec<Unstake>,
+        remaining_unstakes: u32,
+    }
+
+    #[ink(event)]
+    pub struct UnstakeCancelled {
+        #[ink(topic)]
+        caller: AccountId,
+        amount: Balance,
     }
 
     #[ink(event)]
@@ -275,8 +341,33 @@ pub mod staker {
             )
         }
 
-        fn _emit_unstake_event(&self, caller: &AccountId) {
-            EmitEvent::<Staker>::emit_event(self.env(), Unstaked { caller: *caller })
+        fn _emit_unstake_event(
+            &self,
+            caller: &AccountId,
+            recipient: &AccountId,
+            amount: &Balance,
+            unstakes: &Vec<Unstake>,
+            remaining_unstakes: &u32,
+        ) {
+            EmitEvent::<Staker>::emit_event(
+                self.env(),
+                Unstaked {
+                    caller: *caller,
+                    recipient: *recipient,
+                    amount: *amount,
+                    unstakes: unstakes.clone(),
+                    remaining_unstakes: *remaining_unstakes,
+                },
+            )
+        }
+        fn _emit_unstake_cancelled_event(&self, caller: &AccountId, amount: &Balance) {
+            EmitEvent::<Staker>::emit_event(
+                self.env(),
+                UnstakeCancelled {
+                    caller: *caller,
+                    amount: *amount,
+                },
+            );
         }
         fn _emit_rewarded_event(&self, account: &AccountId, amount: &Balance) {
             EmitEvent::<Staker>::emit_event(
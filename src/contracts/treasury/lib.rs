@@ -0,0 +1,251 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#![feature(min_specialization)]
+#[openbrush::implementation(Ownable)]
+#[ink::contract]
+pub mod treasury {
+
+    use abax_governance::contracts_impls::{
+        timestamp_mock::{
+            impls::{
+                TimestampMockImpl,
+                TimestampMockStorage,
+            },
+            traits::*,
+        },
+        treasury::{
+            impls::{
+                TreasuryImpl,
+                TreasuryStorage,
+                TreasuryViewImpl,
+            },
+            traits::*,
+        },
+    };
+
+    // imports from ink!
+    use ink::codegen::{
+        EmitEvent,
+        Env,
+    };
+    // imports from openbrush
+    use openbrush::{
+        contracts::ownable::*,
+        traits::Storage,
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Treasury {
+        #[storage_field]
+        ownable: ownable::Data,
+        #[storage_field]
+        treasury: TreasuryStorage,
+        #[storage_field]
+        timestamp: TimestampMockStorage,
+    }
+
+    // Section contains default implementation without any modifications
+    impl TreasuryViewImpl for Treasury {}
+    impl TreasuryView for Treasury {
+        #[ink(message)]
+        fn available_balance(&self, asset: Asset) -> Balance {
+            TreasuryViewImpl::available_balance(self, asset)
+        }
+
+        #[ink(message)]
+        fn reserved_balance(&self, asset: Asset) -> Balance {
+            TreasuryViewImpl::reserved_balance(self, asset)
+        }
+
+        #[ink(message)]
+        fn stream(&self, stream_id: StreamId) -> Option<Stream> {
+            TreasuryViewImpl::stream(self, stream_id)
+        }
+
+        #[ink(message)]
+        fn withdrawable_from_stream(&self, stream_id: StreamId) -> Balance {
+            TreasuryViewImpl::withdrawable_from_stream(self, stream_id)
+        }
+
+        #[ink(message)]
+        fn number_of_streams(&self) -> StreamId {
+            TreasuryViewImpl::number_of_streams(self)
+        }
+    }
+
+    impl TreasuryImpl for Treasury {}
+    impl abax_governance::contracts_impls::treasury::traits::Treasury for Treasury {
+        #[ink(message)]
+        fn transfer_native(&mut self, to: AccountId, amount: Balance) -> Result<(), TreasuryError> {
+            TreasuryImpl::transfer_native(self, to, amount)
+        }
+
+        #[ink(message)]
+        fn transfer_psp22(&mut self, token: AccountId, to: AccountId, amount: Balance) -> Result<(), TreasuryError> {
+            TreasuryImpl::transfer_psp22(self, token, to, amount)
+        }
+
+        #[ink(message)]
+        fn approve_psp22(
+            &mut self,
+            token: AccountId,
+            spender: AccountId,
+            amount: Balance,
+        ) -> Result<(), TreasuryError> {
+            TreasuryImpl::approve_psp22(self, token, spender, amount)
+        }
+
+        #[ink(message)]
+        fn create_stream(
+            &mut self,
+            asset: Asset,
+            recipient: AccountId,
+            amount: Balance,
+            start: Timestamp,
+            duration: Timestamp,
+        ) -> Result<StreamId, TreasuryError> {
+            TreasuryImpl::create_stream(self, asset, recipient, amount, start, duration)
+        }
+
+        #[ink(message)]
+        fn withdraw_from_stream(&mut self, stream_id: StreamId) -> Result<Balance, TreasuryError> {
+            TreasuryImpl::withdraw_from_stream(self, stream_id)
+        }
+
+        #[ink(message)]
+        fn cancel_stream(&mut self, stream_id: StreamId) -> Result<(), TreasuryError> {
+            TreasuryImpl::cancel_stream(self, stream_id)
+        }
+    }
+
+    impl TimestampMockImpl for Treasury {}
+    impl TimestampMock for Treasury {
+        #[ink(message)]
+        fn set_timestamp_provider(&mut self, account: AccountId) {
+            TimestampMockImpl::set_timestamp_provider(self, account)
+        }
+
+        #[ink(message)]
+        fn timestamp_provider(&self) -> AccountId {
+            TimestampMockImpl::timestamp_provider(self)
+        }
+
+        #[ink(message)]
+        fn timestamp(&self) -> Timestamp {
+            TimestampMockImpl::timestamp(self)
+        }
+    }
+
+    impl Treasury {
+        /// Creates the treasury owned by `governor`, so its funds can be spent only by executed proposals.
+        #[ink(constructor, payable)]
+        pub fn new(governor: AccountId) -> Self {
+            let mut _instance = Self::default();
+            ownable::Internal::_init_with_owner(&mut _instance, governor);
+            _instance
+        }
+
+        /// Accepts native token sent to the treasury.
+        #[ink(message, payable)]
+        pub fn deposit_native(&mut self) {}
+    }
+
+    #[ink(event)]
+    pub struct OwnershipTransferred {
+        #[ink(topic)]
+        previous: Option<AccountId>,
+        #[ink(topic)]
+        new: Option<AccountId>,
+    }
+
+    impl ownable::Internal for Treasury {
+        fn _emit_ownership_transferred_event(&self, previous: Option<AccountId>, new: Option<AccountId>) {
+            EmitEvent::<Treasury>::emit_event(self.env(), OwnershipTransferred { previous, new })
+        }
+    }
+
+    #[ink(event)]
+    pub struct Transferred {
+        asset: Asset,
+        #[ink(topic)]
+        to: AccountId,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct Approved {
+        #[ink(topic)]
+        token: AccountId,
+        #[ink(topic)]
+        spender: AccountId,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct StreamCreated {
+        #[ink(topic)]
+        stream_id: StreamId,
+        stream: Stream,
+    }
+
+    #[ink(event)]
+    pub struct StreamWithdrawn {
+        #[ink(topic)]
+        stream_id: StreamId,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct StreamCancelled {
+        #[ink(topic)]
+        stream_id: StreamId,
+    }
+
+    impl EmitTreasuryEvents for Treasury {
+        fn _emit_transferred_event(&self, asset: &Asset, to: &AccountId, amount: &Balance) {
+            EmitEvent::<Treasury>::emit_event(
+                self.env(),
+                Transferred {
+                    asset: *asset,
+                    to: *to,
+                    amount: *amount,
+                },
+            )
+        }
+
+        fn _emit_approved_event(&self, token: &AccountId, spender: &AccountId, amount: &Balance) {
+            EmitEvent::<Treasury>::emit_event(
+                self.env(),
+                Approved {
+                    token: *token,
+                    spender: *spender,
+                    amount: *amount,
+                },
+            )
+        }
+
+        fn _emit_stream_created_event(&self, stream_id: &StreamId, stream: &Stream) {
+            EmitEvent::<Treasury>::emit_event(
+                self.env(),
+                StreamCreated {
+                    stream_id: *stream_id,
+                    stream: *stream,
+                },
+            )
+        }
+
+        fn _emit_stream_withdrawn_event(&self, stream_id: &StreamId, amount: &Balance) {
+            EmitEvent::<Treasury>::emit_event(
+                self.env(),
+                StreamWithdrawn {
+                    stream_id: *stream_id,
+                    amount: *amount,
+                },
+            )
+        }
+
+        fn _emit_stream_cancelled_event(&self, stream_id: &StreamId) {
+            EmitEvent::<Treasury>::emit_event(self.env(), StreamCancelled { stream_id: *stream_id })
+        }
+    }
+}
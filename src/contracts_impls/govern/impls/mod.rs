@@ -5,6 +5,7 @@ use crate::contracts_impls::{
     stake::{
         impls::{
             storage::data::{
+                StakeCheckpointsStorage,
                 StakeCounterStorage,
                 StakeStorage,
                 StakeTimesStorage,
@@ -14,7 +15,10 @@ use crate::contracts_impls::{
         traits::{
             EmitStakeEvents,
             MathError,
+            StakeCheckpointsInternal,
+            StakeError,
             StakeInternal,
+            StakeTransfer,
         },
     },
     timestamp_mock::impls::{
@@ -38,7 +42,11 @@ use ink::{
 };
 
 use openbrush::{
-    contracts::ownable::only_owner,
+    contracts::ownable::{
+        self,
+        only_owner,
+        OwnableError,
+    },
     modifiers,
     traits::{
         AccountId,
@@ -51,16 +59,56 @@ use openbrush::{
 };
 
 pub use self::storage::{
+    GovernDelegationStorage,
     GovernRewardableSlashableStorage,
     GovernStorage,
+    GovernTreasuryStorage,
 };
 
+/// Separates the payloads signed for `vote_by_sig` from signatures of other messages.
+pub const VOTE_BY_SIG_DOMAIN: &[u8] = b"abax-governor/vote-by-sig/v1";
+
+/// Captures the raw SCALE encoded value returned by the callee.
+pub struct CallOutput(Vec<u8>);
+impl scale::Decode for CallOutput {
+    fn decode<I: scale::Input>(input: &mut I) -> Result<Self, scale::Error> {
+        let len = input.remaining_len()?.unwrap_or_default();
+        let mut output: Vec<u8> = Vec::new();
+        output.resize(len, 0);
+        input.read(&mut output)?;
+        Ok(CallOutput(output))
+    }
+}
+
+/// Raw SCALE encoded arguments of the called transaction.
 pub struct CallInput<'a>(&'a [u8]);
 impl<'a> scale::Encode for CallInput<'a> {
     fn encode_to<T: scale::Output + ?Sized>(&self, dest: &mut T) {
         dest.write(self.0);
     }
 }
+
+/// Calls `tx.callee` and maps the result of the call to its `TransactionOutcome`.
+fn invoke_transaction(tx: &Transaction) -> TransactionOutcome {
+    let result = build_call::<DefaultEnvironment>()
+        .call_type(
+            Call::new(tx.callee)
+                .gas_limit(tx.gas_limit.unwrap_or(0))
+                .transferred_value(tx.transferred_value),
+        )
+        .exec_input(ExecutionInput::new(tx.selector.into()).push_arg(CallInput(&tx.input)))
+        .returns::<CallOutput>()
+        .call_flags(CallFlags::default().set_allow_reentry(true))
+        .try_invoke();
+    match result {
+        Ok(Ok(output)) => TransactionOutcome::Succeeded(output.0),
+        Ok(Err(lang_error)) => TransactionOutcome::LangError(lang_error),
+        Err(ink::env::Error::CalleeReverted) => TransactionOutcome::Reverted,
+        // the callee trapped or couldn't be called
+        Err(_) => TransactionOutcome::Reverted,
+    }
+}
+
 pub const E6: u128 = 10 ^ 6;
 
 impl<T: Storage<GovernStorage> + GovernInternal + Storage<TimestampMockStorage>> GovernView for T {
@@ -76,33 +124,87 @@ impl<T: Storage<GovernStorage> + GovernInternal + Storage<TimestampMockStorage>>
         self.data::<GovernStorage>().rule_allowed(&rules_id)
     }
 
+    fn rules_history(&self, rules_id: RulesId) -> Vec<ProposalRules> {
+        self.data::<GovernStorage>().rules_history(&rules_id)
+    }
+
     fn next_rule_id(&self) -> RulesId {
         self.data::<GovernStorage>().next_rule_id()
     }
 
     fn status(&self, proposal_id: ProposalId) -> Option<ProposalStatus> {
-        self.data::<GovernStorage>().status_of(&proposal_id)
+        self.state(proposal_id).map(|state| state.status)
     }
     fn minimum_to_finalize(&self, proposal_id: ProposalId) -> Result<Balance, GovernError> {
+        self.minimum_to_finalize_at(proposal_id, self._timestamp())
+    }
+
+    fn minimum_to_finalize_at(&self, proposal_id: ProposalId, timestamp: Timestamp) -> Result<Balance, GovernError> {
         let proposal = self
             .data::<GovernStorage>()
             .state_of(&proposal_id)
             .ok_or(GovernError::ProposalDoesntExist)?;
         let rules = self
             .data::<GovernStorage>()
-            .rule(&proposal.rules_id)
+            .rule_at_version(&proposal.rules_id, proposal.rules_version)
             .ok_or(GovernError::NoSuchRule)?;
-        let timestamp = self._timestamp();
         Ok(self._minimum_to_finalize(&proposal, &rules, timestamp))
     }
 
     fn state(&self, proposal_id: ProposalId) -> Option<ProposalState> {
-        self.data::<GovernStorage>().state_of(&proposal_id)
+        let mut state = self.data::<GovernStorage>().state_of(&proposal_id)?;
+        if self._is_expired(&state, self._timestamp()) {
+            state.status = ProposalStatus::Expired;
+        }
+        Some(state)
+    }
+
+    fn proposal(&self, proposal_id: ProposalId) -> Option<Proposal> {
+        self.data::<GovernStorage>().proposal_of(&proposal_id)
     }
 
     fn vote_of_for(&self, account: AccountId, proposal_id: ProposalId) -> Option<UserVote> {
         self.data::<GovernStorage>().vote_of_for(&account, &proposal_id)
     }
+
+    fn vote_nonce_of(&self, account: AccountId) -> u64 {
+        self.data::<GovernStorage>().vote_nonce_of(&account)
+    }
+
+    fn vote_by_sig_payload(
+        &self,
+        voter: AccountId,
+        proposal_id: ProposalId,
+        vote: Vote,
+        nonce: u64,
+        deadline: Timestamp,
+    ) -> Vec<u8> {
+        self._vote_by_sig_payload(&voter, &proposal_id, &vote, nonce, deadline)
+    }
+
+    fn vote_reason_of(&self, account: AccountId, proposal_id: ProposalId) -> Option<Vec<u8>> {
+        self.data::<GovernStorage>().vote_reason_of(&account, &proposal_id)
+    }
+
+    fn proposals(&self, offset: u32, limit: u32) -> Vec<(ProposalId, ProposalState)> {
+        let proposal_ids = self.data::<GovernStorage>().proposal_ids(offset, limit);
+        proposal_ids
+            .into_iter()
+            .filter_map(|proposal_id| self.state(proposal_id).map(|state| (proposal_id, state)))
+            .collect()
+    }
+
+    fn active_proposal_ids(&self, offset: u32, limit: u32) -> Vec<ProposalId> {
+        self.data::<GovernStorage>().active_proposal_ids(offset, limit)
+    }
+
+    fn proposals_of_proposer(&self, account: AccountId, offset: u32, limit: u32) -> Vec<(ProposalId, ProposalState)> {
+        let proposal_ids = self.data::<GovernStorage>().proposal_ids_of(&account, offset, limit);
+        proposal_ids
+            .into_iter()
+            .filter_map(|proposal_id| self.state(proposal_id).map(|state| (proposal_id, state)))
+            .collect()
+    }
 }
 
 impl<
@@ -141,40 +243,90 @@ impl<
         Ok(())
     }
 
+    /// # Storage modifications
+    /// [GovernStorage]
+    /// `state` - of key `proposal_id` is modified by changing the status field to `Cancelled`.
+    fn cancel(&mut self, proposal_id: ProposalId) -> Result<(), GovernError> {
+        self._cancel(&proposal_id)?;
+        Ok(())
+    }
+
+    /// # Storage modifications
+    /// [GovernStorage]
+    /// `state` - of key `proposal_id` is modified by changing the status field to `Queued` and setting `eta`.
+    fn queue(&mut self, proposal_id: ProposalId) -> Result<(), GovernError> {
+        self._queue(&proposal_id)?;
+        Ok(())
+    }
+
     fn execute(&mut self, proposal: Proposal, description_hash: [u8; 32]) -> Result<(), GovernError> {
         let proposal_id = self._hash_proposal(&proposal, &description_hash);
         self._execute(&proposal_id, &proposal)?;
         Ok(())
     }
 
+    fn execute_by_id(&mut self, proposal_id: ProposalId) -> Result<(), GovernError> {
+        let proposal = self
+            .data::<GovernStorage>()
+            .proposal_of(&proposal_id)
+            .ok_or(GovernError::ProposalDoesntExist)?;
+        self._execute(&proposal_id, &proposal)?;
+        Ok(())
+    }
+
     /// # Storage modification
     /// [GovernStorage]
-    /// `votes` of key (`proposal_id`, `caller`) is set based on `stake` of key `caller` and parameter `vote`.
+    /// `votes` of key (`proposal_id`, `caller`) is set based on `stake` of key `caller` at `state.snapshot()` and parameter `vote`.
     /// `state` of key `proposal_id` votes fields are updated.
-    fn vote(
-        &mut self,
-        proposal_id: ProposalId,
-        vote: Vote,
-        #[allow(unused_variables)] reason: Vec<u8>,
-    ) -> Result<(), GovernError> {
+    /// `vote_reasons` of key (`caller`, `proposal_id`) is set to `reason` if rules allow to store vote reasons.
+    fn vote(&mut self, proposal_id: ProposalId, vote: Vote, reason: Vec<u8>) -> Result<(), GovernError> {
         ink::env::debug_println!("vote | START");
         let caller = Self::env().caller();
-        let state = self
-            .data::<GovernStorage>()
-            .state_of(&proposal_id)
-            .ok_or(GovernError::ProposalDoesntExist)?;
-        ink::env::debug_println!("vote | pull data");
-
-        let amount = self
-            .data::<StakeStorage>()
-            .stake_and_unstakes_initialized_after(&caller, &state.start);
-        self.data::<GovernStorage>()
-            .update_vote_of_for(&caller, &proposal_id, &vote, &amount)?;
-        self._emit_vote_casted_event(&caller, &proposal_id, &vote);
+        self._cast_vote(&caller, &proposal_id, &vote, &reason)?;
         ink::env::debug_println!("vote | STOP");
 
         Ok(())
     }
+
+    /// # Storage modification
+    /// [GovernStorage]
+    /// for each successful vote:
+    /// `votes` of key (`proposal_id`, `caller`) is set based on `stake` of key `caller` at `state.snapshot()` and parameter `vote`.
+    /// `state` of key `proposal_id` votes fields are updated.
+    fn vote_batch(&mut self, votes: Vec<(ProposalId, Vote)>) -> Vec<Result<(), GovernError>> {
+        let caller = Self::env().caller();
+        votes
+            .iter()
+            .map(|(proposal_id, vote)| self._cast_vote(&caller, proposal_id, vote, &Vec::new()))
+            .collect()
+    }
+
+    /// # Storage modification
+    /// [GovernStorage]
+    /// `vote_nonces` of key `voter` increased by 1.
+    /// `votes` of key (`proposal_id`, `voter`) is set based on `stake` of key `voter` at `state.snapshot()` and parameter `vote`.
+    /// `state` of key `proposal_id` votes fields are updated.
+    fn vote_by_sig(
+        &mut self,
+        voter: AccountId,
+        proposal_id: ProposalId,
+        vote: Vote,
+        nonce: u64,
+        deadline: Timestamp,
+        signature: VoteSignature,
+    ) -> Result<(), GovernError> {
+        if self._timestamp() > deadline {
+            return Err(GovernError::SignatureExpired)
+        }
+        if nonce != self.data::<GovernStorage>().vote_nonce_of(&voter) {
+            return Err(GovernError::InvalidNonce)
+        }
+        let payload = self._vote_by_sig_payload(&voter, &proposal_id, &vote, nonce, deadline);
+        self._verify_vote_signature(&voter, &payload, &signature)?;
+
+        self.data::<GovernStorage>().vote_nonces.insert(&voter, &(nonce + 1));
+        self._cast_vote(&voter, &proposal_id, &vote, &Vec::new())
+    }
 }
 
 impl<
@@ -196,6 +348,10 @@ impl<
         self._claimed_or_slashed(&account, &proposal_id)
     }
 
+    fn is_slashable(&self, account: AccountId, proposal_id: ProposalId) -> Result<Balance, GovernError> {
+        self._voter_penalty_of(&account, &proposal_id)
+    }
+
     /// # Storage modifications
     /// [GovernRewardableSlashableStorage]
     /// `claimed_or_slashed` of key `caller` set to ().
@@ -213,13 +369,26 @@ impl<
         Ok(())
     }
 
+    /// # Storage modifications
+    /// [GovernRewardableSlashableStorage]
+    /// `claimed_or_slashed` of keys (`caller`, `proposal_id`) set to () for each successfully claimed `proposal_id`.
+    /// [StakeStorage]
+    /// `stake` of key `caller` increased by the sum of rewards.
+    /// `total_stake` increased by the sum of rewards.
+    /// [StakeCounterStorage]
+    /// `counter_stake` increased by the sum of rewards.
+    fn claim_rewards(&mut self, proposal_ids: Vec<ProposalId>) -> Result<Vec<Result<(), GovernError>>, GovernError> {
+        let caller = Self::env().caller();
+        self._reward_voter_for_many(&caller, &proposal_ids)
+    }
+
     /// # Storage modifications
     /// [GovernRewardableSlashableStorage]
     /// `claimed_or_slashed` of key `caller` set to ().
     /// [StakeStorage]
     /// `stakes` of key `caller` decreased by max(`amount`,`stakes` of key `caller`). Ig becomes 0 then remove.
     /// `total_stake` decreased by max max(`amount`,`stakes` of key `caller`).
-    /// `unstakes` of keys `(caller, unstakes_id.0..unstakes_id.1)` field amount decreased appropriately to cover rest of max(`amount` - `stakes`, 0) of slash.
+    /// `unstakes_queue` of keys `(caller, unstakes_id.0..unstakes_id.1)` field amount decreased appropriately to cover rest of max(`amount` - `stakes`, 0) of slash.
     /// `total_unstake` decreased by appropriately
     /// [StakeTimesStorage]
     /// `stakes_timestamps` of key `caller` removed if `stakes` of key `caller` was removed.
@@ -228,6 +397,66 @@ impl<
         self._slash_voter(&account, &proposal_id)?;
         Ok(())
     }
+
+    /// # Storage modifications
+    /// Same as `slash_voter` for each of successfully slashed `accounts`.
+    fn slash_voters(
+        &mut self,
+        proposal_id: ProposalId,
+        accounts: Vec<AccountId>,
+    ) -> Result<Vec<Result<Balance, GovernError>>, GovernError> {
+        let caller = Self::env().caller();
+        self._slash_voters(&caller, &proposal_id, &accounts)
+    }
+}
+
+impl<T: Storage<GovernTreasuryStorage> + Storage<openbrush::contracts::ownable::Data> + GovernTreasuryInternal>
+    GovernTreasury for T
+{
+    fn treasury_balance(&self) -> TreasuryBalance {
+        self.data::<GovernTreasuryStorage>().balance()
+    }
+
+    /// # Storage modifications
+    /// [GovernTreasuryStorage]
+    /// `native` or `want` decreased by `amount`.
+    #[modifiers(only_owner())]
+    fn treasury_transfer(&mut self, asset: TreasuryAsset, to: AccountId, amount: Balance) -> Result<(), GovernError> {
+        self._withdraw_from_treasury(&asset, &to, &amount)
+    }
+}
+
+impl<T: Storage<GovernDelegationStorage> + GovernDelegateInternal> GovernDelegate for T {
+    /// # Storage modifications
+    /// [GovernDelegationStorage]
+    /// `delegates` of key `caller` set to `to`.
+    /// `delegated_power_checkpoints` of old delegate and `to` updated by `stakes` of key `caller`.
+    fn delegate(&mut self, to: AccountId) -> Result<(), GovernError> {
+        let caller = Self::env().caller();
+        self._set_delegate(&caller, &Some(to))?;
+        Ok(())
+    }
+
+    /// # Storage modifications
+    /// [GovernDelegationStorage]
+    /// `delegates` of key `caller` removed.
+    /// `delegated_power_checkpoints` of old delegate decreased by `stakes` of key `caller`.
+    fn undelegate(&mut self) -> Result<(), GovernError> {
+        let caller = Self::env().caller();
+        if self.data::<GovernDelegationStorage>().delegate_of(&caller).is_none() {
+            return Err(GovernError::NotDelegating)
+        }
+        self._set_delegate(&caller, &None)?;
+        Ok(())
+    }
+
+    fn delegate_of(&self, account: AccountId) -> Option<AccountId> {
+        self.data::<GovernDelegationStorage>().delegate_of(&account)
+    }
+
+    fn delegated_power_of(&self, account: AccountId) -> Balance {
+        self.data::<GovernDelegationStorage>().delegated_power_of(&account)
+    }
 }
 
 impl<T: Storage<GovernStorage> + Storage<openbrush::contracts::ownable::Data> + GovernInternal + EmitGovernEvents>
@@ -243,6 +472,18 @@ impl<T: Storage<GovernStorage> + Storage<openbrush::contracts::ownable::Data> +
         self._emit_proposal_rule_added_event(&at_rule_id, &rules);
         Ok(())
     }
+
+    /// # Storage modifications
+    /// [GovernStorage]
+    /// `rules` of key `rules_id` set to `rules`
+    /// `rules_versions` of key (`rules_id`, new version) set to `rules`
+    /// `number_of_rules_versions` of key `rules_id` increased by 1.
+    #[modifiers(only_owner())]
+    fn update_rules(&mut self, rules_id: RulesId, rules: ProposalRules) -> Result<(), GovernError> {
+        let version = self.data::<GovernStorage>().update_rule(&rules_id, &rules)?;
+        self._emit_proposal_rules_updated_event(&rules_id, &version, &rules);
+        Ok(())
+    }
     /// # Storage modifications
     /// [GovernStorage]
     /// `rules_allowed` of key `rule_id` set to () if `allow` is true or else removed.
@@ -260,8 +501,14 @@ impl<
             + StakeInternal
             + Storage<StakeTimesStorage>
             + Storage<StakeCounterStorage>
+            + Storage<StakeCheckpointsStorage>
+            + StakeCheckpointsInternal
+            + Storage<GovernDelegationStorage>
+            + Storage<ownable::Data>
             + Storage<TimestampMockStorage>
             + TimestampMockInternal
+            + GovernTreasuryInternal
+            + GovernHooks
             + EmitStakeEvents
             + EmitGovernEvents,
     > GovernInternal for T
@@ -311,6 +558,8 @@ impl<
     /// # Storage modifications
     /// [GovernStorage]
     /// `state` of key `proposal_id` set based on `proposal`
+    /// `proposals` of key `proposal_id` set to `proposal`
+    /// `proposal_id` added to `proposal_ids`, `active_proposal_ids` and `proposer_proposal_ids`.
     fn _register_proposal(
         &mut self,
         proposal_id: &ProposalId,
@@ -321,10 +570,12 @@ impl<
             return Err(GovernError::ProposalAlreadyExists)
         }
 
+        let caller = Self::env().caller();
+        self._before_propose(&caller, proposal_id, proposal)?;
+
         let timestamp = self._timestamp();
         let counter_at_start = self.data::<StakeCounterStorage>().counter_stake;
         let votes_at_start = self.data::<StakeStorage>().total_stake;
-        let caller = Self::env().caller();
         self.data::<GovernStorage>().state.insert(
             &proposal_id,
             &ProposalState {
@@ -332,25 +583,121 @@ impl<
                 proposer: caller,
                 voter_reward_part_e12: proposal.voter_reward_part_e12,
                 rules_id: proposal.rules_id,
+                rules_version: self.data::<GovernStorage>().rules_version(&proposal.rules_id),
                 start: timestamp,
                 counter_at_start,
                 votes_at_start,
                 finalized: None,
+                eta: None,
                 votes_for: 0,
                 votes_against: 0,
                 votes_against_with_slash: 0,
+                votes_abstain: 0,
             },
         );
 
-        self.data::<GovernStorage>().active_proposals += 1;
+        self.data::<GovernStorage>().proposals.insert(&proposal_id, proposal);
+        self.data::<GovernStorage>().add_proposal_id(&proposal_id, &caller);
 
         self._emit_proposal_created_event(&proposal_id, proposal, description);
         Ok(())
     }
 
+    /// # Storage modification
+    /// [GovernStorage]
+    /// `votes` of key (`proposal_id`, `voter`) is set based on `stake` of key `voter` at `state.snapshot()` and parameter `vote`.
+    /// `state` of key `proposal_id` votes fields are updated.
+    /// `vote_reasons` of key (`voter`, `proposal_id`) is set to `reason` if rules allow to store vote reasons.
+    fn _cast_vote(
+        &mut self,
+        voter: &AccountId,
+        proposal_id: &ProposalId,
+        vote: &Vote,
+        reason: &Vec<u8>,
+    ) -> Result<(), GovernError> {
+        let state = self
+            .data::<GovernStorage>()
+            .state_of(proposal_id)
+            .ok_or(GovernError::ProposalDoesntExist)?;
+        let rules = self
+            .data::<GovernStorage>()
+            .rule_at_version(&state.rules_id, state.rules_version)
+            .ok_or(GovernError::NoSuchRule)?;
+        if reason.len() > rules.maximal_vote_reason_length as usize {
+            return Err(GovernError::VoteReasonTooLong)
+        }
+
+        let amount = self._get_votes_at(voter, &state.snapshot());
+        self.data::<GovernStorage>()
+            .update_vote_of_for(voter, proposal_id, vote, &amount)?;
+        if rules.store_vote_reasons && !reason.is_empty() {
+            self.data::<GovernStorage>()
+                .vote_reasons
+                .insert(&(*voter, *proposal_id), reason);
+        } else {
+            self.data::<GovernStorage>()
+                .vote_reasons
+                .remove(&(*voter, *proposal_id));
+        }
+        self._after_vote(voter, proposal_id, vote, &amount)?;
+        self._emit_vote_casted_event(voter, proposal_id, vote, reason);
+        Ok(())
+    }
+
+    fn _vote_by_sig_payload(
+        &self,
+        voter: &AccountId,
+        proposal_id: &ProposalId,
+        vote: &Vote,
+        nonce: u64,
+        deadline: Timestamp,
+    ) -> Vec<u8> {
+        scale::Encode::encode(&(
+            VOTE_BY_SIG_DOMAIN,
+            Self::env().account_id(),
+            voter,
+            proposal_id,
+            vote,
+            nonce,
+            deadline,
+        ))
+    }
+
+    fn _verify_vote_signature(
+        &self,
+        voter: &AccountId,
+        payload: &[u8],
+        signature: &VoteSignature,
+    ) -> Result<(), GovernError> {
+        match signature {
+            VoteSignature::Ecdsa(signature) => {
+                let mut message_hash = [0u8; 32];
+                ink::env::hash_bytes::<Blake2x256>(payload, &mut message_hash);
+                let mut public_key = [0u8; 33];
+                ink::env::ecdsa_recover(signature, &message_hash, &mut public_key)
+                    .map_err(|_| GovernError::InvalidSignature)?;
+                let mut signer = [0u8; 32];
+                ink::env::hash_bytes::<Blake2x256>(&public_key, &mut signer);
+                if AccountId::from(signer) != *voter {
+                    return Err(GovernError::InvalidSignature)
+                }
+                Ok(())
+            }
+            VoteSignature::Sr25519(signature) => {
+                ink::env::sr25519_verify(signature, payload, voter.as_ref()).map_err(|_| GovernError::InvalidSignature)
+            }
+        }
+    }
+
     fn _get_votes_at(&self, account: &AccountId, timestamp: &Timestamp) -> Balance {
-        self.data::<StakeStorage>()
-            .stake_and_unstakes_initialized_after(account, timestamp)
+        let own_votes = match self.data::<GovernDelegationStorage>().delegate_at(account, timestamp) {
+            Some(_) => 0,
+            None => self._stake_at(account, timestamp),
+        };
+        own_votes.saturating_add(
+            self.data::<GovernDelegationStorage>()
+                .delegated_power_at(account, timestamp),
+        )
     }
 
     fn _minimum_to_finalize(&self, state: &ProposalState, rules: &ProposalRules, now: Timestamp) -> Balance {
@@ -361,21 +708,50 @@ impl<
         let counter_diff = self.data::<StakeCounterStorage>().counter_stake - state.counter_at_start;
         let total_votes = counter_diff + state.votes_at_start;
 
-        if now <= end_initial_period {
-            ink::env::debug_println!("initial");
-            total_votes / 2 * (end_initial_period - now) as u128 / rules.initial_period as u128 + total_votes / 2
-        } else if now <= end_flat_period {
-            ink::env::debug_println!("mid");
-            total_votes / 2
-        } else if now <= end_final_period {
-            ink::env::debug_println!("final");
-            total_votes / 2 * (end_final_period - now) as u128 / rules.final_period as u128
-        } else {
+        if now > end_final_period {
             ink::env::debug_println!("last");
-            0
+            return 0
+        }
+        // the curve is sampled from the start of the proposal
+        let now = now.max(state.start);
+
+        match rules.finalization_curve {
+            FinalizationCurve::Linear3Phase => {
+                if now <= end_initial_period {
+                    ink::env::debug_println!("initial");
+                    total_votes / 2 * (end_initial_period - now) as u128 / rules.initial_period as u128
+                        + total_votes / 2
+                } else if now <= end_flat_period {
+                    ink::env::debug_println!("mid");
+                    total_votes / 2
+                } else {
+                    ink::env::debug_println!("final");
+                    total_votes / 2 * (end_final_period - now) as u128 / rules.final_period as u128
+                }
+            }
+            FinalizationCurve::FixedMajority(part_e12) | FinalizationCurve::Supermajority(part_e12) => {
+                total_votes.saturating_mul(part_e12 as u128) / E12
+            }
+            FinalizationCurve::ExponentialDecay(half_life) => {
+                let elapsed = now.saturating_sub(state.start);
+                let halvings = elapsed / half_life;
+                if halvings >= u128::BITS as u64 {
+                    return 0
+                }
+                let at_last_halving = total_votes >> halvings;
+                // linear interpolation between halvings
+                at_last_halving - at_last_halving / 2 * (elapsed % half_life) as u128 / half_life as u128
+            }
         }
     }
 
+    fn _quorum(&self, state: &ProposalState, rules: &ProposalRules) -> Balance {
+        let counter_diff = self.data::<StakeCounterStorage>().counter_stake - state.counter_at_start;
+        let total_votes = counter_diff + state.votes_at_start;
+
+        total_votes.saturating_mul(rules.quorum_part_e12 as u128) / E12
+    }
+
     /// # Storage modifications
     /// [GovernStorage]
     /// `state` of key `proposal_id` field status set to apropariate status, field finalized set to `block_timestamp`.
@@ -388,15 +764,34 @@ impl<
         if state.status != ProposalStatus::Active {
             return Err(GovernError::NotActive)
         }
+        self._before_finalize(proposal_id, &state)?;
 
         let rules = self
             .data::<GovernStorage>()
-            .rule(&state.rules_id)
+            .rule_at_version(&state.rules_id, state.rules_version)
             .ok_or(GovernError::NoSuchRule)?;
 
-        let minimum_to_finalize = self._minimum_to_finalize(&state, &rules, self._timestamp());
+        let now = self._timestamp();
+        let minimum_to_finalize = self._minimum_to_finalize(&state, &rules, now);
+        let minimum_to_defeat = match rules.finalization_curve {
+            // the proposal is defeated once the supermajority can't be reached
+            FinalizationCurve::Supermajority(_) if minimum_to_finalize > 0 => {
+                let counter_diff = self.data::<StakeCounterStorage>().counter_stake - state.counter_at_start;
+                (counter_diff + state.votes_at_start).saturating_sub(minimum_to_finalize) + 1
+            }
+            _ => minimum_to_finalize,
+        };
+        let quorum_reached =
+            state.votes_for + state.votes_against + state.votes_against_with_slash + state.votes_abstain
+                >= self._quorum(&state, &rules);
 
-        if state.votes_against + state.votes_against_with_slash >= minimum_to_finalize {
+        if self._is_expired(&state, now) {
+            state.status = ProposalStatus::Expired;
+            match ink::env::transfer::<DefaultEnvironment>(state.proposer, rules.deposit) {
+                Ok(()) => (),
+                Err(_v) => return Err(GovernError::TransferError),
+            };
+        } else if state.votes_against + state.votes_against_with_slash >= minimum_to_defeat && quorum_reached {
             if state.votes_against_with_slash <= state.votes_against + state.votes_for {
                 state.status = ProposalStatus::Defeated;
                 match ink::env::transfer::<DefaultEnvironment>(state.proposer, rules.deposit) {
@@ -412,9 +807,11 @@ impl<
                     .checked_mul(slash_part_e12)
                     .ok_or(MathError::Mul)?
                     / E12;
-                self._slash(&state.proposer, &slash_amount)?;
+                let slashed = self._slash(&state.proposer, &slash_amount)?;
+                self._deposit_to_treasury(&TreasuryAsset::Native, &rules.deposit)?;
+                self._deposit_to_treasury(&TreasuryAsset::Want, &slashed)?;
             }
-        } else if state.votes_for >= minimum_to_finalize {
+        } else if state.votes_for >= minimum_to_finalize && quorum_reached {
             state.status = ProposalStatus::Succeeded;
             match ink::env::transfer::<DefaultEnvironment>(state.proposer, rules.deposit) {
                 Ok(()) => (),
@@ -426,13 +823,122 @@ impl<
         state.finalized = Some(self._timestamp());
 
         self.data::<GovernStorage>().state.insert(&proposal_id, &state);
-        self.data::<GovernStorage>().active_proposals -= 1;
+        self.data::<GovernStorage>().remove_active_proposal_id(&proposal_id);
         self.data::<GovernStorage>().finalized_proposals += 1;
+        self._after_finalize(proposal_id, &state.status)?;
 
         self._emit_proposal_finalized_event(&proposal_id, &state.status);
         Ok(())
     }
 
+    /// # Storage modifications
+    /// [GovernStorage]
+    /// `state` of key `proposal_id` status set to Cancelled.
+    /// `proposal_id` removed from `active_proposal_ids`.
+    fn _cancel(&mut self, proposal_id: &ProposalId) -> Result<(), GovernError> {
+        let mut state = self
+            .data::<GovernStorage>()
+            .state_of(&proposal_id)
+            .ok_or(GovernError::ProposalDoesntExist)?;
+
+        if state.status != ProposalStatus::Active {
+            return Err(GovernError::NotActive)
+        }
+
+        let rules = self
+            .data::<GovernStorage>()
+            .rule_at_version(&state.rules_id, state.rules_version)
+            .ok_or(GovernError::NoSuchRule)?;
+
+        let caller = Self::env().caller();
+        let no_votes = state.votes_for == 0
+            && state.votes_against == 0
+            && state.votes_against_with_slash == 0
+            && state.votes_abstain == 0;
+
+        let return_deposit = if caller == state.proposer && no_votes {
+            true
+        } else if caller == self.data::<ownable::Data>().owner {
+            rules.owner_cancel_returns_deposit
+        } else if caller == state.proposer {
+            return Err(GovernError::CancelCondition)
+        } else {
+            return Err(OwnableError::CallerIsNotOwner.into())
+        };
+
+        if return_deposit {
+            match ink::env::transfer::<DefaultEnvironment>(state.proposer, rules.deposit) {
+                Ok(()) => (),
+                Err(_v) => return Err(GovernError::TransferError),
+            };
+        } else {
+            self._deposit_to_treasury(&TreasuryAsset::Native, &rules.deposit)?;
+        }
+
+        state.status = ProposalStatus::Cancelled;
+
+        self.data::<GovernStorage>().state.insert(&proposal_id, &state);
+        self.data::<GovernStorage>().remove_active_proposal_id(&proposal_id);
+
+        self._emit_proposal_cancelled_event(&proposal_id, &caller);
+        Ok(())
+    }
+
+    /// # Storage modifications
+    /// [GovernStorage]
+    /// `state` of key `proposal_id` status set to Queued, eta set to `block_timestamp` + `rules.execution_delay`.
+    fn _queue(&mut self, proposal_id: &ProposalId) -> Result<(), GovernError> {
+        let mut state = self
+            .data::<GovernStorage>()
+            .state_of(&proposal_id)
+            .ok_or(GovernError::ProposalDoesntExist)?;
+        if state.status != ProposalStatus::Succeeded {
+            return Err(GovernError::WrongStatus)
+        }
+
+        let rules = self
+            .data::<GovernStorage>()
+            .rule_at_version(&state.rules_id, state.rules_version)
+            .ok_or(GovernError::NoSuchRule)?;
+
+        let eta = self
+            ._timestamp()
+            .checked_add(rules.execution_delay)
+            .ok_or(MathError::Add)?;
+        state.status = ProposalStatus::Queued;
+        state.eta = Some(eta);
+
+        self.data::<GovernStorage>().state.insert(&proposal_id, &state);
+
+        self._emit_proposal_queued_event(&proposal_id, &eta);
+        Ok(())
+    }
+
+    fn _is_expired(&self, state: &ProposalState, now: Timestamp) -> bool {
+        let rules = match self
+            .data::<GovernStorage>()
+            .rule_at_version(&state.rules_id, state.rules_version)
+        {
+            Some(rules) => rules,
+            None => return false,
+        };
+        match state.status {
+            ProposalStatus::Queued => {
+                match state.eta {
+                    Some(eta) => now > eta.saturating_add(rules.execution_grace_period),
+                    None => false,
+                }
+            }
+            ProposalStatus::Active => {
+                let end_final_period = state.start + rules.initial_period + rules.flat_period + rules.final_period;
+                now > end_final_period
+                    && state.votes_for + state.votes_against + state.votes_against_with_slash + state.votes_abstain
+                        < self._quorum(state, &rules)
+            }
+            _ => false,
+        }
+    }
+
     /// # Storage modifications
     /// [GovernStorage]
     /// `state` of key `proposal_id` status set to Executed
@@ -441,45 +947,53 @@ impl<
             .data::<GovernStorage>()
             .state_of(&proposal_id)
             .ok_or(GovernError::ProposalDoesntExist)?;
-        if state.status != ProposalStatus::Succeeded {
+        if state.status != ProposalStatus::Queued {
             return Err(GovernError::WrongStatus)
         }
+        let now = self._timestamp();
+        if now < state.eta.unwrap_or_default() {
+            return Err(GovernError::TimelockNotPassed)
+        }
+        if self._is_expired(&state, now) {
+            return Err(GovernError::ProposalExpired)
+        }
+        self._before_execute(proposal_id, proposal)?;
 
-        for tx in &proposal.transactions {
+        let mut outcomes: Vec<TransactionOutcome> = Vec::new();
+        for (index, tx) in proposal.transactions.iter().enumerate() {
             self.flush();
-            let result = build_call::<DefaultEnvironment>()
-                .call_type(
-                    Call::new(tx.callee)
-                        .gas_limit(0)
-                        .transferred_value(tx.transferred_value),
-                )
-                .exec_input(ExecutionInput::new(tx.selector.into()).push_arg(CallInput(&tx.input)))
-                .returns::<()>()
-                .call_flags(CallFlags::default().set_allow_reentry(true))
-                .try_invoke()
-                .map_err(|_| GovernError::UnderlyingTransactionReverted);
+            let outcome = invoke_transaction(tx);
             self.load();
-            result?.unwrap()
+
+            if !matches!(outcome, TransactionOutcome::Succeeded(_)) && !tx.allow_failure {
+                return Err(GovernError::UnderlyingTransactionReverted(index as u32))
+            }
+            outcomes.push(outcome);
         }
 
         state.status = ProposalStatus::Executed;
 
         self.data::<GovernStorage>().state.insert(&proposal_id, &state);
 
-        self._emit_proposal_executed_event(&proposal_id);
+        self._emit_proposal_executed_event(&proposal_id, &outcomes);
         Ok(())
     }
 }
 
 impl<
         T: Storage<GovernStorage>
+            + GovernInternal
             + Storage<GovernRewardableSlashableStorage>
+            + Storage<GovernDelegationStorage>
             + Storage<StakeStorage>
             + StakeInternal
             + Storage<StakeTimesStorage>
             + Storage<StakeCounterStorage>
+            + Storage<StakeCheckpointsStorage>
+            + StakeCheckpointsInternal
             + Storage<TimestampMockStorage>
             + TimestampMockInternal
+            + GovernTreasuryInternal
             + EmitStakeEvents
             + EmitGovernEvents,
     > GovernRewardableSlashableInternal for T
@@ -509,6 +1023,51 @@ impl<
     /// `stakes_timestamps` of key `account set to `block_timestamp` if None.
     /// `last_stakes_timestamps` of key account set to `block_timestamp`
     fn _reward_voter(&mut self, account: &AccountId, proposal_id: &ProposalId) -> Result<(), GovernError> {
+        let reward = self._voter_reward_of(account, proposal_id)?;
+        self._reward(account, &reward)?;
+        self.data::<GovernRewardableSlashableStorage>()
+            .claimed_or_slashed
+            .insert(&(*account, *proposal_id), &());
+
+        self._emit_voter_rewarded_event(account, proposal_id);
+        Ok(())
+    }
+
+    /// # Storage modifications
+    /// [GovernRewardableSlashableStorage]
+    /// `claimed_or_slashed` of keys (`account`, `proposal_id`) set to () for each successfully claimed `proposal_id`.
+    /// [StakeStorage]
+    /// `stake` of key `account` increased by the sum of rewards.
+    /// `total_stake` increased by the sum of rewards.
+    /// [StakeCounterStorage]
+    /// `counter_stake` increased by the sum of rewards.
+    fn _reward_voter_for_many(
+        &mut self,
+        account: &AccountId,
+        proposal_ids: &Vec<ProposalId>,
+    ) -> Result<Vec<Result<(), GovernError>>, GovernError> {
+        let mut results: Vec<Result<(), GovernError>> = Vec::new();
+        let mut total_reward: Balance = 0;
+        for proposal_id in proposal_ids {
+            match self._voter_reward_of(account, proposal_id) {
+                Ok(reward) => {
+                    total_reward = total_reward.checked_add(reward).ok_or(MathError::Add)?;
+                    self.data::<GovernRewardableSlashableStorage>()
+                        .claimed_or_slashed
+                        .insert(&(*account, *proposal_id), &());
+                    self._emit_voter_rewarded_event(account, proposal_id);
+                    results.push(Ok(()));
+                }
+                Err(error) => results.push(Err(error)),
+            }
+        }
+        if total_reward > 0 {
+            self._reward(account, &total_reward)?;
+        }
+        Ok(results)
+    }
+
+    fn _voter_reward_of(&self, account: &AccountId, proposal_id: &ProposalId) -> Result<Balance, GovernError> {
         let state = self
             .data::<GovernStorage>()
             .state_of(proposal_id)
@@ -516,26 +1075,41 @@ impl<
         if state.status == ProposalStatus::Active {
             return Err(GovernError::StillActive)
         }
+        if state.status == ProposalStatus::Cancelled {
+            return Err(GovernError::WrongStatus)
+        }
         if self._claimed_or_slashed(account, proposal_id) {
             return Err(GovernError::AlreadyClaimedOrSlashed)
         }
-        let vote = self
-            .data::<GovernStorage>()
-            .vote_of_for(account, proposal_id)
-            .ok_or(GovernError::DidntVote)?;
+        let rewarded_votes = match self.data::<GovernStorage>().vote_of_for(account, proposal_id) {
+            Some(vote) => {
+                // power delegated to `account` is rewarded to the delegators
+                vote.amount.saturating_sub(
+                    self.data::<GovernDelegationStorage>()
+                        .delegated_power_at(account, &state.snapshot()),
+                )
+            }
+            None => {
+                let delegate = self
+                    .data::<GovernDelegationStorage>()
+                    .delegate_at(account, &state.snapshot())
+                    .ok_or(GovernError::DidntVote)?;
+                if self
+                    .data::<GovernStorage>()
+                    .vote_of_for(&delegate, proposal_id)
+                    .is_none()
+                {
+                    return Err(GovernError::DidntVote)
+                }
+                self._stake_at(account, &state.snapshot())
+            }
+        };
 
-        let reward = vote
-            .amount
+        let reward = rewarded_votes
             .checked_mul(state.voter_reward_part_e12 as u128)
             .ok_or(MathError::Mul)?
             / E12;
-        self._reward(account, &reward)?;
-        self.data::<GovernRewardableSlashableStorage>()
-            .claimed_or_slashed
-            .insert(&(*account, *proposal_id), &());
-
-        self._emit_voter_rewarded_event(account, proposal_id);
-        Ok(())
+        Ok(reward)
     }
 
     /// # Storage modifications
@@ -544,12 +1118,25 @@ impl<
     /// [StakeStorage]
     /// `stakes` of key `account` decreased by max(`amount`,`stakes` of key `account`). Ig becomes 0 then remove.
     /// `total_stake` decreased by max max(`amount`,`stakes` of key `account`).
-    /// `unstakes` of keys `(account, unstakes_id.0..unstakes_id.1)` field amount decreased appropriately to cover rest of max(`amount` - `stakes`, 0) of slash.
+    /// `unstakes_queue` of keys `(account, unstakes_id.0..unstakes_id.1)` field amount decreased appropriately to cover rest of max(`amount` - `stakes`, 0) of slash.
     /// `total_unstake` decreased by appropriately
     /// [StakeTimesStorage]
     /// `stakes_timestamps` of key `account` removed if `stakes` of key `account` was removed.
     /// `last_stakes_timestamps` oof key `account` removed if `stakes` of key `account` was removed.
-    fn _slash_voter(&mut self, account: &AccountId, proposal_id: &ProposalId) -> Result<(), GovernError> {
+    fn _slash_voter(&mut self, account: &AccountId, proposal_id: &ProposalId) -> Result<Balance, GovernError> {
+        let penalty = self._voter_penalty_of(account, proposal_id)?;
+
+        let slashed = self._slash(account, &penalty)?;
+        self._deposit_to_treasury(&TreasuryAsset::Want, &slashed)?;
+
+        self.data::<GovernRewardableSlashableStorage>()
+            .claimed_or_slashed
+            .insert(&(*account, *proposal_id), &());
+        self._emit_voter_slashed_event(account, proposal_id);
+        Ok(slashed)
+    }
+
+    fn _voter_penalty_of(&self, account: &AccountId, proposal_id: &ProposalId) -> Result<Balance, GovernError> {
         let state = self
             .data::<GovernStorage>()
             .state_of(proposal_id)
@@ -557,17 +1144,18 @@ impl<
         if state.status == ProposalStatus::Active {
             return Err(GovernError::StillActive)
         }
+        if state.status == ProposalStatus::Cancelled {
+            return Err(GovernError::WrongStatus)
+        }
         let rules = self
             .data::<GovernStorage>()
-            .rule(&state.rules_id)
+            .rule_at_version(&state.rules_id, state.rules_version)
             .ok_or(GovernError::NoSuchRule)?;
         if state.finalized.unwrap() <= state.start + rules.initial_period + rules.flat_period {
             return Err(GovernError::NothingToSlash)
         }
-        if self.data::<StakeTimesStorage>().stake_timestamp_of(&account).is_some() {
-            if self.data::<StakeTimesStorage>().stake_timestamp_of(account).unwrap()
-                > state.finalized.unwrap() - 24 * 60 * 60 * 1000
-            {
+        if let Some(stake_timestamp) = self.data::<StakeTimesStorage>().stake_timestamp_of(account) {
+            if stake_timestamp > state.finalized.unwrap().saturating_sub(rules.voter_slash_grace_period) {
                 return Err(GovernError::NothingToSlash)
             }
         }
@@ -578,22 +1166,235 @@ impl<
         if self.data::<GovernStorage>().vote_of_for(account, proposal_id).is_some() {
             return Err(GovernError::Voted)
         };
+        if let Some(delegate) = self
+            .data::<GovernDelegationStorage>()
+            .delegate_at(account, &state.snapshot())
+        {
+            if self
+                .data::<GovernStorage>()
+                .vote_of_for(&delegate, proposal_id)
+                .is_some()
+            {
+                return Err(GovernError::Voted)
+            }
+        }
 
-        let stake_at_start = self
-            .data::<StakeStorage>()
-            .stake_and_unstakes_initialized_after(account, &state.start);
+        let stake_at_start = self._stake_at(account, &state.snapshot());
 
         let penalty = stake_at_start
             .checked_mul(rules.voter_slash_part_e12 as u128)
             .ok_or(MathError::Mul)?
             / E12;
+        if penalty == 0 {
+            return Err(GovernError::NothingToSlash)
+        }
 
-        self._slash(account, &penalty)?;
+        Ok(penalty)
+    }
 
-        self.data::<GovernRewardableSlashableStorage>()
-            .claimed_or_slashed
-            .insert(&(*account, *proposal_id), &());
-        self._emit_voter_slashed_event(account, proposal_id);
+    /// # Storage modifications
+    /// Same as `_slash_voter` for each of successfully slashed `accounts`.
+    fn _slash_voters(
+        &mut self,
+        keeper: &AccountId,
+        proposal_id: &ProposalId,
+        accounts: &Vec<AccountId>,
+    ) -> Result<Vec<Result<Balance, GovernError>>, GovernError> {
+        let state = self
+            .data::<GovernStorage>()
+            .state_of(proposal_id)
+            .ok_or(GovernError::ProposalDoesntExist)?;
+        let rules = self
+            .data::<GovernStorage>()
+            .rule_at_version(&state.rules_id, state.rules_version)
+            .ok_or(GovernError::NoSuchRule)?;
+
+        let mut results: Vec<Result<Balance, GovernError>> = Vec::new();
+        let mut total_slashed: Balance = 0;
+        for account in accounts {
+            let result = self._slash_voter(account, proposal_id);
+            if let Ok(slashed) = result {
+                total_slashed = total_slashed.checked_add(slashed).ok_or(MathError::Add)?;
+            }
+            results.push(result);
+        }
+
+        let bounty = total_slashed
+            .checked_mul(rules.slash_bounty_part_e12 as u128)
+            .ok_or(MathError::Mul)?
+            / E12;
+        self._withdraw_from_treasury(&TreasuryAsset::Want, keeper, &bounty)?;
+        Ok(results)
+    }
+}
+
+impl<T: Storage<GovernStorage>> GovernHooks for T {
+    default fn _before_propose(
+        &mut self,
+        _proposer: &AccountId,
+        _proposal_id: &ProposalId,
+        _proposal: &Proposal,
+    ) -> Result<(), GovernError> {
+        Ok(())
+    }
+
+    default fn _after_vote(
+        &mut self,
+        _voter: &AccountId,
+        _proposal_id: &ProposalId,
+        _vote: &Vote,
+        _amount: &Balance,
+    ) -> Result<(), GovernError> {
+        Ok(())
+    }
+
+    default fn _before_finalize(
+        &mut self,
+        _proposal_id: &ProposalId,
+        _state: &ProposalState,
+    ) -> Result<(), GovernError> {
+        Ok(())
+    }
+
+    default fn _after_finalize(
+        &mut self,
+        _proposal_id: &ProposalId,
+        _status: &ProposalStatus,
+    ) -> Result<(), GovernError> {
+        Ok(())
+    }
+
+    default fn _before_execute(&mut self, _proposal_id: &ProposalId, _proposal: &Proposal) -> Result<(), GovernError> {
+        Ok(())
+    }
+}
+
+impl<T: Storage<GovernTreasuryStorage> + StakeTransfer + EmitGovernEvents> GovernTreasuryInternal for T {
+    /// # Storage modifications
+    /// [GovernTreasuryStorage]
+    /// `native` or `want` increased by `amount`.
+    fn _deposit_to_treasury(&mut self, asset: &TreasuryAsset, amount: &Balance) -> Result<(), GovernError> {
+        if *amount == 0 {
+            return Ok(())
+        }
+        self.data::<GovernTreasuryStorage>().increase_balance(asset, amount)?;
+        self._emit_treasury_deposited_event(asset, amount);
+        Ok(())
+    }
+
+    /// # Storage modifications
+    /// [GovernTreasuryStorage]
+    /// `native` or `want` decreased by `amount`.
+    fn _take_from_treasury(&mut self, asset: &TreasuryAsset, amount: &Balance) -> Result<(), GovernError> {
+        if *amount == 0 {
+            return Ok(())
+        }
+        self.data::<GovernTreasuryStorage>().decrease_balance(asset, amount)?;
+        self._emit_treasury_withdrawn_event(asset, &Self::env().account_id(), amount);
+        Ok(())
+    }
+
+    /// # Storage modifications
+    /// [GovernTreasuryStorage]
+    /// `native` or `want` decreased by `amount`.
+    fn _withdraw_from_treasury(
+        &mut self,
+        asset: &TreasuryAsset,
+        to: &AccountId,
+        amount: &Balance,
+    ) -> Result<(), GovernError> {
+        if *amount == 0 {
+            return Ok(())
+        }
+        self.data::<GovernTreasuryStorage>().decrease_balance(asset, amount)?;
+        match asset {
+            TreasuryAsset::Native => {
+                match ink::env::transfer::<DefaultEnvironment>(*to, *amount) {
+                    Ok(()) => (),
+                    Err(_v) => return Err(GovernError::TransferError),
+                };
+            }
+            TreasuryAsset::Want => self._transfer_out(to, amount)?,
+        }
+        self._emit_treasury_withdrawn_event(asset, to, amount);
+        Ok(())
+    }
+}
+
+impl<
+        T: Storage<GovernDelegationStorage>
+            + Storage<StakeStorage>
+            + Storage<StakeCheckpointsStorage>
+            + Storage<TimestampMockStorage>
+            + TimestampMockInternal
+            + StakeCheckpointsInternal
+            + EmitGovernEvents,
+    > GovernDelegateInternal for T
+{
+    /// # Storage modifications
+    /// [GovernDelegationStorage]
+    /// `delegates` of key `account` set to `delegate` or removed if `delegate` is None.
+    /// `delegate_checkpoints` of key `account` updated at `block_timestamp`.
+    /// `delegated_power_checkpoints` of old delegate decreased and of new delegate increased by `stakes` of key `account`.
+    /// [StakeCheckpointsStorage]
+    /// checkpoint with `stakes` of key `account` is written at timestamp 0 if `account` has no checkpoints.
+    fn _set_delegate(&mut self, account: &AccountId, delegate: &Option<AccountId>) -> Result<(), GovernError> {
+        if *delegate == Some(*account) {
+            return Err(GovernError::SelfDelegation)
+        }
+        let timestamp = self._timestamp();
+        // the stake moved to the delegate must be in the checkpoint history, so that later changes of it are moved as well
+        self._backfill_stake_checkpoint_of(account);
+        let stake = self.data::<StakeStorage>().stake_of(account);
+        let old_delegate = self.data::<GovernDelegationStorage>().delegate_of(account);
+
+        if let Some(old_delegate) = old_delegate {
+            self.data::<GovernDelegationStorage>()
+                .decrease_delegated_power_of(&old_delegate, &stake, &timestamp)?;
+        }
+        if let Some(new_delegate) = delegate {
+            self.data::<GovernDelegationStorage>()
+                .increase_delegated_power_of(new_delegate, &stake, &timestamp)?;
+        }
+        self.data::<GovernDelegationStorage>()
+            .set_delegate(account, delegate, &timestamp);
+
+        if let Some(old_delegate) = old_delegate {
+            self._emit_undelegated_event(account, &old_delegate);
+        }
+        if let Some(new_delegate) = delegate {
+            self._emit_delegated_event(account, new_delegate);
+        }
+        Ok(())
+    }
+
+    /// # Storage modifications
+    /// [StakeCheckpointsStorage]
+    /// checkpoint with `stakes` of key `account` is written at `block_timestamp`.
+    /// [GovernDelegationStorage]
+    /// `delegated_power_checkpoints` of `account` delegate changed by the change of `stakes` of key `account`.
+    fn _checkpoint_stake_and_delegated_power_of(&mut self, account: &AccountId) -> Result<(), StakeError> {
+        let timestamp = self._timestamp();
+        let old_stake = self.data::<StakeCheckpointsStorage>().latest_stake_of(account);
+        let new_stake = self.data::<StakeStorage>().stake_of(account);
+        self.data::<StakeCheckpointsStorage>()
+            .write_checkpoint(account, &timestamp, &new_stake);
+
+        if let Some(delegate) = self.data::<GovernDelegationStorage>().delegate_of(account) {
+            if new_stake >= old_stake {
+                self.data::<GovernDelegationStorage>().increase_delegated_power_of(
+                    &delegate,
+                    &(new_stake - old_stake),
+                    &timestamp,
+                )?;
+            } else {
+                self.data::<GovernDelegationStorage>().decrease_delegated_power_of(
+                    &delegate,
+                    &(old_stake - new_stake),
+                    &timestamp,
+                )?;
+            }
+        }
         Ok(())
     }
 }
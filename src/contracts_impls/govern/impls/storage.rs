@@ -1,8 +1,10 @@
+use ink::prelude::vec::Vec;
 use openbrush::{
     storage::Mapping,
     traits::{
         AccountId,
         Balance,
+        Timestamp,
     },
 };
 
@@ -13,21 +15,88 @@ use crate::contracts_impls::{
         ProposalId,
         RulesId,
     },
-    stake::impls::E12,
+    stake::{
+        impls::{
+            storage::data::checkpoint_index_at,
+            E12,
+        },
+        traits::{
+            Checkpoint,
+            MathError,
+        },
+    },
 };
 pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(GovernStorage);
+/// The layout of the stored values isn't backward compatible: `ProposalRules` and `ProposalState` were extended with new fields
+/// (among others `rules_version`, `eta`, `votes_abstain`, the quorum, the execution and the vote reason parameters),
+/// so values written by the contract versions before these extensions can't be decoded.
+/// The governor storing such values can't be upgraded with `set_code_hash` - a new governor has to be deployed instead.
 #[derive(Debug, Default)]
 #[openbrush::storage_item]
 pub struct GovernStorage {
     // parameters
     pub rules: Mapping<RulesId, ProposalRules>,
+    pub rules_versions: Mapping<(RulesId, u32), ProposalRules>,
+    pub number_of_rules_versions: Mapping<RulesId, u32>,
     pub allowed_rules: Mapping<RulesId, ()>,
     pub next_rule_id: RulesId,
     // data
     pub active_proposals: u32,
     pub finalized_proposals: u32,
     pub state: Mapping<ProposalId, ProposalState>,
+    pub proposals: Mapping<ProposalId, Proposal>,
     pub votes: Mapping<(AccountId, ProposalId), UserVote>,
+    pub vote_reasons: Mapping<(AccountId, ProposalId), Vec<u8>>,
+    pub vote_nonces: Mapping<AccountId, u64>,
+    // enumeration
+    pub next_proposal_index: u32,
+    pub proposal_ids: Mapping<u32, ProposalId>,
+    pub active_proposal_ids: Mapping<u32, ProposalId>,
+    pub active_proposal_index: Mapping<ProposalId, u32>,
+    pub proposer_proposal_ids: Mapping<(AccountId, u32), ProposalId>,
+    pub number_of_proposals_of: Mapping<AccountId, u32>,
+}
+
+fn validate_rules(rules: &ProposalRules) -> Result<(), GovernError> {
+    if rules.proposer_slash_part_e12 as u128 > E12
+        || rules.voter_slash_part_e12 as u128 > E12
+        || rules.quorum_part_e12 as u128 > E12
+        || rules.slash_bounty_part_e12 as u128 > E12
+    {
+        return Err(GovernError::WrongParameters)
+    }
+    // `_minimum_to_finalize` divides by these periods
+    if rules.initial_period == 0 || rules.final_period == 0 {
+        return Err(GovernError::WrongParameters)
+    }
+    // grace period longer than the voting would exempt every staker from slashing
+    let voting_period = rules
+        .initial_period
+        .checked_add(rules.flat_period)
+        .and_then(|period| period.checked_add(rules.final_period))
+        .ok_or(GovernError::WrongParameters)?;
+    if rules.voter_slash_grace_period > voting_period {
+        return Err(GovernError::WrongParameters)
+    }
+    match rules.finalization_curve {
+        FinalizationCurve::Linear3Phase => (),
+        FinalizationCurve::FixedMajority(part_e12) | FinalizationCurve::Supermajority(part_e12) => {
+            if part_e12 as u128 > E12 {
+                return Err(GovernError::WrongParameters)
+            }
+        }
+        FinalizationCurve::ExponentialDecay(half_life) => {
+            if half_life == 0 {
+                return Err(GovernError::WrongParameters)
+            }
+        }
+    }
+    Ok(())
+}
+
+fn page<F: Fn(u32) -> Option<ProposalId>>(length: u32, offset: u32, limit: u32, id_at: F) -> Vec<ProposalId> {
+    let end = offset.saturating_add(limit).min(length);
+    (offset..end).filter_map(id_at).collect()
 }
 
 impl GovernStorage {
@@ -35,6 +104,25 @@ impl GovernStorage {
         self.rules.get(&rules_id)
     }
 
+    pub fn rule_at_version(&self, rules_id: &RulesId, version: u32) -> Option<ProposalRules> {
+        self.rules_versions.get(&(*rules_id, version))
+    }
+
+    /// returns the version of the current rules identified by `rules_id`.
+    pub fn rules_version(&self, rules_id: &RulesId) -> u32 {
+        self.number_of_rules_versions
+            .get(rules_id)
+            .unwrap_or_default()
+            .saturating_sub(1)
+    }
+
+    pub fn rules_history(&self, rules_id: &RulesId) -> Vec<ProposalRules> {
+        let number_of_versions = self.number_of_rules_versions.get(rules_id).unwrap_or_default();
+        (0..number_of_versions)
+            .filter_map(|version| self.rule_at_version(rules_id, version))
+            .collect()
+    }
+
     pub fn rule_allowed(&self, rules_id: &RulesId) -> bool {
         self.allowed_rules.get(&rules_id).is_some()
     }
@@ -54,10 +142,75 @@ impl GovernStorage {
         self.state.get(proposal_id)
     }
 
+    pub fn proposal_of(&self, proposal_id: &ProposalId) -> Option<Proposal> {
+        self.proposals.get(proposal_id)
+    }
+
+    pub fn proposal_ids(&self, offset: u32, limit: u32) -> Vec<ProposalId> {
+        page(self.next_proposal_index, offset, limit, |index| {
+            self.proposal_ids.get(&index)
+        })
+    }
+
+    pub fn active_proposal_ids(&self, offset: u32, limit: u32) -> Vec<ProposalId> {
+        page(self.active_proposals, offset, limit, |index| {
+            self.active_proposal_ids.get(&index)
+        })
+    }
+
+    pub fn proposal_ids_of(&self, proposer: &AccountId, offset: u32, limit: u32) -> Vec<ProposalId> {
+        let number_of_proposals = self.number_of_proposals_of.get(proposer).unwrap_or_default();
+        page(number_of_proposals, offset, limit, |index| {
+            self.proposer_proposal_ids.get(&(*proposer, index))
+        })
+    }
+
+    /// adds `proposal_id` at the end of all, active and `proposer` proposals.
+    pub fn add_proposal_id(&mut self, proposal_id: &ProposalId, proposer: &AccountId) {
+        self.proposal_ids.insert(&self.next_proposal_index, proposal_id);
+        self.next_proposal_index += 1;
+
+        self.active_proposal_ids.insert(&self.active_proposals, proposal_id);
+        self.active_proposal_index.insert(proposal_id, &self.active_proposals);
+        self.active_proposals += 1;
+
+        let number_of_proposals = self.number_of_proposals_of.get(proposer).unwrap_or_default();
+        self.proposer_proposal_ids
+            .insert(&(*proposer, number_of_proposals), proposal_id);
+        self.number_of_proposals_of.insert(proposer, &(number_of_proposals + 1));
+    }
+
+    /// removes `proposal_id` from active proposals by replacing it with the last active proposal.
+    /// does nothing if `proposal_id` is not active.
+    pub fn remove_active_proposal_id(&mut self, proposal_id: &ProposalId) {
+        if let (Some(index), Some(last_index)) = (
+            self.active_proposal_index.get(proposal_id),
+            self.active_proposals.checked_sub(1),
+        ) {
+            if index != last_index {
+                if let Some(last_proposal_id) = self.active_proposal_ids.get(&last_index) {
+                    self.active_proposal_ids.insert(&index, &last_proposal_id);
+                    self.active_proposal_index.insert(&last_proposal_id, &index);
+                }
+            }
+            self.active_proposal_ids.remove(&last_index);
+            self.active_proposal_index.remove(proposal_id);
+            self.active_proposals = last_index;
+        }
+    }
+
     pub fn vote_of_for(&self, account: &AccountId, proposal_id: &ProposalId) -> Option<UserVote> {
         self.votes.get(&(*account, *proposal_id))
     }
 
+    pub fn vote_reason_of(&self, account: &AccountId, proposal_id: &ProposalId) -> Option<Vec<u8>> {
+        self.vote_reasons.get(&(*account, *proposal_id))
+    }
+
+    pub fn vote_nonce_of(&self, account: &AccountId) -> u64 {
+        self.vote_nonces.get(account).unwrap_or_default()
+    }
+
     pub fn update_vote_of_for(
         &mut self,
         account: &AccountId,
@@ -73,68 +226,20 @@ impl GovernStorage {
             return Err(GovernError::NotActive)
         }
 
-        let user_vote = self.vote_of_for(account, &proposal_id);
-        match user_vote {
-            None => {
-                match vote {
-                    Vote::Agreed => state.votes_for += *amount,
-                    Vote::Disagreed => state.votes_against += *amount,
-                    Vote::DisagreedWithProposerSlashing => state.votes_against_with_slash += *amount,
-                }
-            }
-            Some(old_vote) => {
-                match old_vote.vote {
-                    Vote::Agreed => {
-                        match vote {
-                            Vote::Agreed => {
-                                state.votes_for -= old_vote.amount;
-                                state.votes_for += *amount;
-                            }
-                            Vote::Disagreed => {
-                                state.votes_for -= old_vote.amount;
-                                state.votes_against += *amount;
-                            }
-                            Vote::DisagreedWithProposerSlashing => {
-                                state.votes_for -= old_vote.amount;
-                                state.votes_against_with_slash += *amount;
-                            }
-                        }
-                    }
-                    Vote::Disagreed => {
-                        match vote {
-                            Vote::Agreed => {
-                                state.votes_against -= old_vote.amount;
-                                state.votes_for += *amount;
-                            }
-                            Vote::Disagreed => {
-                                state.votes_against -= old_vote.amount;
-                                state.votes_against += *amount;
-                            }
-                            Vote::DisagreedWithProposerSlashing => {
-                                state.votes_against -= old_vote.amount;
-                                state.votes_against_with_slash += *amount;
-                            }
-                        }
-                    }
-                    Vote::DisagreedWithProposerSlashing => {
-                        match vote {
-                            Vote::Agreed => {
-                                state.votes_against_with_slash -= old_vote.amount;
-                                state.votes_for += *amount;
-                            }
-                            Vote::Disagreed => {
-                                state.votes_against_with_slash -= old_vote.amount;
-                                state.votes_against += *amount;
-                            }
-                            Vote::DisagreedWithProposerSlashing => {
-                                state.votes_against_with_slash -= old_vote.amount;
-                                state.votes_against_with_slash += *amount;
-                            }
-                        }
-                    }
-                }
+        if let Some(old_vote) = self.vote_of_for(account, &proposal_id) {
+            match old_vote.vote {
+                Vote::Agreed => state.votes_for -= old_vote.amount,
+                Vote::Disagreed => state.votes_against -= old_vote.amount,
+                Vote::DisagreedWithProposerSlashing => state.votes_against_with_slash -= old_vote.amount,
+                Vote::Abstained => state.votes_abstain -= old_vote.amount,
             }
         }
+        match vote {
+            Vote::Agreed => state.votes_for += *amount,
+            Vote::Disagreed => state.votes_against += *amount,
+            Vote::DisagreedWithProposerSlashing => state.votes_against_with_slash += *amount,
+            Vote::Abstained => state.votes_abstain += *amount,
+        }
 
         let new_vote = UserVote {
             vote: *vote,
@@ -148,17 +253,32 @@ impl GovernStorage {
     }
 
     pub fn add_new_rule(&mut self, rules: &ProposalRules) -> Result<u64, GovernError> {
-        if rules.proposer_slash_part_e12 as u128 > E12 || rules.voter_slash_part_e12 as u128 > E12 {
-            return Err(GovernError::WrongParameters)
-        }
+        validate_rules(rules)?;
         let next_rule_id = self.next_rule_id;
 
         self.rules.insert(&(next_rule_id), rules);
+        self.rules_versions.insert(&(next_rule_id, 0), rules);
+        self.number_of_rules_versions.insert(&next_rule_id, &1);
 
         self.next_rule_id = next_rule_id + 1;
         Ok(next_rule_id)
     }
 
+    /// sets `rules` as the new version of rules identified by `rules_id`. Returns the new version.
+    pub fn update_rule(&mut self, rules_id: &RulesId, rules: &ProposalRules) -> Result<u32, GovernError> {
+        if self.rule(&rules_id).is_none() {
+            return Err(GovernError::NoSuchRule)
+        }
+        validate_rules(rules)?;
+        let version = self.number_of_rules_versions.get(rules_id).unwrap_or_default();
+
+        self.rules.insert(rules_id, rules);
+        self.rules_versions.insert(&(*rules_id, version), rules);
+        self.number_of_rules_versions.insert(rules_id, &(version + 1));
+
+        Ok(version)
+    }
+
     pub fn allow_rules(&mut self, rules_id: &RulesId, allow: &bool) -> Result<(), GovernError> {
         if self.rule(&rules_id).is_none() {
             return Err(GovernError::NoSuchRule)
@@ -182,3 +302,187 @@ impl GovernStorage {
 pub struct GovernRewardableSlashableStorage {
     pub claimed_or_slashed: Mapping<(AccountId, ProposalId), ()>,
 }
+
+#[derive(Debug, Default)]
+#[openbrush::storage_item]
+pub struct GovernTreasuryStorage {
+    pub native: Balance,
+    pub want: Balance,
+}
+
+impl GovernTreasuryStorage {
+    pub fn balance(&self) -> TreasuryBalance {
+        TreasuryBalance {
+            native: self.native,
+            want: self.want,
+        }
+    }
+
+    pub fn increase_balance(&mut self, asset: &TreasuryAsset, amount: &Balance) -> Result<(), GovernError> {
+        let balance = match asset {
+            TreasuryAsset::Native => &mut self.native,
+            TreasuryAsset::Want => &mut self.want,
+        };
+        *balance = balance.checked_add(*amount).ok_or(MathError::Add)?;
+        Ok(())
+    }
+
+    pub fn decrease_balance(&mut self, asset: &TreasuryAsset, amount: &Balance) -> Result<(), GovernError> {
+        let balance = match asset {
+            TreasuryAsset::Native => &mut self.native,
+            TreasuryAsset::Want => &mut self.want,
+        };
+        *balance = balance
+            .checked_sub(*amount)
+            .ok_or(GovernError::InsufficientTreasuryBalance)?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default)]
+#[openbrush::storage_item]
+pub struct GovernDelegationStorage {
+    pub delegates: Mapping<AccountId, AccountId>,
+    pub delegate_checkpoints: Mapping<(AccountId, u32), DelegateCheckpoint>,
+    pub number_of_delegate_checkpoints: Mapping<AccountId, u32>,
+    pub delegated_power_checkpoints: Mapping<(AccountId, u32), Checkpoint>,
+    pub number_of_delegated_power_checkpoints: Mapping<AccountId, u32>,
+}
+
+impl GovernDelegationStorage {
+    pub fn delegate_of(&self, account: &AccountId) -> Option<AccountId> {
+        self.delegates.get(account)
+    }
+
+    pub fn delegate_at(&self, account: &AccountId, timestamp: &Timestamp) -> Option<AccountId> {
+        let number_of_checkpoints = self.number_of_delegate_checkpoints.get(account).unwrap_or_default();
+        let index = checkpoint_index_at(number_of_checkpoints, timestamp, |index| {
+            self.delegate_checkpoints
+                .get(&(*account, index))
+                .map(|checkpoint| checkpoint.timestamp)
+        });
+        match index {
+            Some(index) => {
+                self.delegate_checkpoints
+                    .get(&(*account, index))
+                    .and_then(|checkpoint| checkpoint.delegate)
+            }
+            None => None,
+        }
+    }
+
+    pub fn delegated_power_of(&self, account: &AccountId) -> Balance {
+        let number_of_checkpoints = self
+            .number_of_delegated_power_checkpoints
+            .get(account)
+            .unwrap_or_default();
+        if number_of_checkpoints == 0 {
+            return 0
+        }
+        self.delegated_power_checkpoints
+            .get(&(*account, number_of_checkpoints - 1))
+            .map(|checkpoint| checkpoint.stake)
+            .unwrap_or_default()
+    }
+
+    pub fn delegated_power_at(&self, account: &AccountId, timestamp: &Timestamp) -> Balance {
+        let number_of_checkpoints = self
+            .number_of_delegated_power_checkpoints
+            .get(account)
+            .unwrap_or_default();
+        let index = checkpoint_index_at(number_of_checkpoints, timestamp, |index| {
+            self.delegated_power_checkpoints
+                .get(&(*account, index))
+                .map(|checkpoint| checkpoint.timestamp)
+        });
+        match index {
+            Some(index) => {
+                self.delegated_power_checkpoints
+                    .get(&(*account, index))
+                    .map(|checkpoint| checkpoint.stake)
+                    .unwrap_or_default()
+            }
+            None => 0,
+        }
+    }
+
+    pub fn set_delegate(&mut self, account: &AccountId, delegate: &Option<AccountId>, timestamp: &Timestamp) {
+        match delegate {
+            Some(delegate) => self.delegates.insert(account, delegate),
+            None => self.delegates.remove(account),
+        };
+
+        let number_of_checkpoints = self.number_of_delegate_checkpoints.get(account).unwrap_or_default();
+        let new_checkpoint = DelegateCheckpoint {
+            timestamp: *timestamp,
+            delegate: *delegate,
+        };
+        if number_of_checkpoints > 0 {
+            let last_index = number_of_checkpoints - 1;
+            if let Some(last_checkpoint) = self.delegate_checkpoints.get(&(*account, last_index)) {
+                if last_checkpoint.timestamp == *timestamp {
+                    self.delegate_checkpoints
+                        .insert(&(*account, last_index), &new_checkpoint);
+                    return
+                }
+            }
+        }
+        self.delegate_checkpoints
+            .insert(&(*account, number_of_checkpoints), &new_checkpoint);
+        self.number_of_delegate_checkpoints
+            .insert(account, &(number_of_checkpoints + 1));
+    }
+
+    pub fn increase_delegated_power_of(
+        &mut self,
+        account: &AccountId,
+        amount: &Balance,
+        timestamp: &Timestamp,
+    ) -> Result<(), MathError> {
+        let new_power = self
+            .delegated_power_of(account)
+            .checked_add(*amount)
+            .ok_or(MathError::Add)?;
+        self.write_delegated_power_checkpoint(account, &new_power, timestamp);
+        Ok(())
+    }
+
+    pub fn decrease_delegated_power_of(
+        &mut self,
+        account: &AccountId,
+        amount: &Balance,
+        timestamp: &Timestamp,
+    ) -> Result<(), MathError> {
+        let new_power = self
+            .delegated_power_of(account)
+            .checked_sub(*amount)
+            .ok_or(MathError::Sub)?;
+        self.write_delegated_power_checkpoint(account, &new_power, timestamp);
+        Ok(())
+    }
+
+    fn write_delegated_power_checkpoint(&mut self, account: &AccountId, power: &Balance, timestamp: &Timestamp) {
+        let number_of_checkpoints = self
+            .number_of_delegated_power_checkpoints
+            .get(account)
+            .unwrap_or_default();
+        let new_checkpoint = Checkpoint {
+            timestamp: *timestamp,
+            stake: *power,
+        };
+        if number_of_checkpoints > 0 {
+            let last_index = number_of_checkpoints - 1;
+            if let Some(last_checkpoint) = self.delegated_power_checkpoints.get(&(*account, last_index)) {
+                if last_checkpoint.timestamp == *timestamp {
+                    self.delegated_power_checkpoints
+                        .insert(&(*account, last_index), &new_checkpoint);
+                    return
+                }
+            }
+        }
+        self.delegated_power_checkpoints
+            .insert(&(*account, number_of_checkpoints), &new_checkpoint);
+        self.number_of_delegated_power_checkpoints
+            .insert(account, &(number_of_checkpoints + 1));
+    }
+}
@@ -26,7 +26,7 @@ pub enum GovernError {
     NotActive,
     FinalizeCondition,
     WrongStatus,
-    UnderlyingTransactionReverted,
+    UnderlyingTransactionReverted(u32),
     TransferError,
     StillActive,
     RewardMultiplier,
@@ -38,6 +38,16 @@ pub enum GovernError {
     RuleNotAllowed,
     OwnableError(OwnableError),
     WrongParameters,
+    SelfDelegation,
+    NotDelegating,
+    CancelCondition,
+    TimelockNotPassed,
+    ProposalExpired,
+    VoteReasonTooLong,
+    InvalidSignature,
+    InvalidNonce,
+    SignatureExpired,
+    InsufficientTreasuryBalance,
 }
 
 impl From<MathError> for GovernError {
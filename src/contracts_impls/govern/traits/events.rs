@@ -1,7 +1,9 @@
 use openbrush::traits::{
     AccountId,
+    Balance,
     Hash,
     String,
+    Timestamp,
 };
 
 use super::{
@@ -10,20 +12,32 @@ use super::{
     ProposalRules,
     ProposalStatus,
     RulesId,
+    TransactionOutcome,
+    TreasuryAsset,
     Vote,
 };
+use ink::prelude::vec::Vec;
 
 pub trait EmitGovernEvents {
     fn _emit_proposal_created_event(&self, proposal_id: &ProposalId, proposal: &Proposal, description: &String);
     fn _emit_proposal_finalized_event(&self, proposal_id: &ProposalId, status: &ProposalStatus);
-    fn _emit_proposal_executed_event(&self, proposal_id: &ProposalId);
+    fn _emit_proposal_queued_event(&self, proposal_id: &ProposalId, eta: &Timestamp);
+    fn _emit_proposal_executed_event(&self, proposal_id: &ProposalId, outcomes: &Vec<TransactionOutcome>);
+    fn _emit_proposal_cancelled_event(&self, proposal_id: &ProposalId, caller: &AccountId);
 
-    fn _emit_vote_casted_event(&self, account: &AccountId, proposal_id: &ProposalId, vote: &Vote);
+    fn _emit_vote_casted_event(&self, account: &AccountId, proposal_id: &ProposalId, vote: &Vote, reason: &Vec<u8>);
 
     fn _emit_voter_rewarded_event(&self, account: &AccountId, proposal_id: &ProposalId);
     fn _emit_voter_slashed_event(&self, account: &AccountId, proposal_id: &Hash);
 
     fn _emit_proposal_rule_added_event(&self, rules_id: &RulesId, rules: &ProposalRules);
+    fn _emit_proposal_rules_updated_event(&self, rules_id: &RulesId, version: &u32, rules: &ProposalRules);
 
     fn _emit_rules_allowed_event(&self, rules_id: &RulesId, allowed: &bool);
+
+    fn _emit_delegated_event(&self, delegator: &AccountId, delegate: &AccountId);
+    fn _emit_undelegated_event(&self, delegator: &AccountId, delegate: &AccountId);
+
+    fn _emit_treasury_deposited_event(&self, asset: &TreasuryAsset, amount: &Balance);
+    fn _emit_treasury_withdrawn_event(&self, asset: &TreasuryAsset, to: &AccountId, amount: &Balance);
 }
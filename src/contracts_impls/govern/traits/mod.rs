@@ -16,6 +16,8 @@ use openbrush::traits::{
 
 use ink::prelude::vec::Vec;
 
+use crate::contracts_impls::stake::traits::StakeError;
+
 pub type ProposalId = Hash;
 pub type RulesId = u64;
 
@@ -33,11 +35,17 @@ pub trait GovernView {
     #[ink(message)]
     fn rules_allowed(&self, rules_id: RulesId) -> bool;
 
+    /// Returns all versions of rules identified by `rules_id`, from the oldest to the current one.
+    #[ink(message)]
+    fn rules_history(&self, rules_id: RulesId) -> Vec<ProposalRules>;
+
     /// Returns number of rules + 1
     #[ink(message)]
     fn next_rule_id(&self) -> RulesId;
 
     /// Returns ProposalStatus of proposal with proposal_id (proposal Hash).
+    /// `Queued` proposal that wasn't executed during the execution grace period is returned as `Expired`.
+    /// `Active` proposal that didn't reach the quorum until the end of the final period is returned as `Expired`.
     #[ink(message)]
     fn status(&self, proposal_id: ProposalId) -> Option<ProposalStatus>;
 
@@ -45,13 +53,54 @@ pub trait GovernView {
     #[ink(message)]
     fn minimum_to_finalize(&self, proposal_id: ProposalId) -> Result<Balance, GovernError>;
 
+    /// Returns minimum to finalize proposal at `timestamp` sampled from the finalization curve of the proposal rules.
+    /// The total votes are taken at the current timestamp.
+    #[ink(message)]
+    fn minimum_to_finalize_at(&self, proposal_id: ProposalId, timestamp: Timestamp) -> Result<Balance, GovernError>;
+
     /// Returns ProposalStatus of proposal with proposal_id (proposal Hash).
     #[ink(message)]
     fn state(&self, proposal_id: ProposalId) -> Option<ProposalState>;
 
+    /// Returns the `Proposal` identified by `proposal_id`. None if proposal doesn't exist.
+    #[ink(message)]
+    fn proposal(&self, proposal_id: ProposalId) -> Option<Proposal>;
+
     /// Returns Some(UserVote) `account` has voted for `proposal_id` and None if hasn't.
     #[ink(message)]
     fn vote_of_for(&self, account: AccountId, proposal_id: ProposalId) -> Option<UserVote>;
+
+    /// Returns the nonce that has to be signed by `account` in the next `vote_by_sig`.
+    #[ink(message)]
+    fn vote_nonce_of(&self, account: AccountId) -> u64;
+
+    /// Returns the SCALE encoded payload that has to be signed by `voter` to vote with `vote_by_sig`.
+    #[ink(message)]
+    fn vote_by_sig_payload(
+        &self,
+        voter: AccountId,
+        proposal_id: ProposalId,
+        vote: Vote,
+        nonce: u64,
+        deadline: Timestamp,
+    ) -> Vec<u8>;
+
+    /// Returns the stored reason of the `account` vote for `proposal_id`. None if the reason wasn't stored.
+    #[ink(message)]
+    fn vote_reason_of(&self, account: AccountId, proposal_id: ProposalId) -> Option<Vec<u8>>;
+
+    /// Returns at most `limit` proposals with their states in the order of creation, starting from the `offset`-th proposal.
+    #[ink(message)]
+    fn proposals(&self, offset: u32, limit: u32) -> Vec<(ProposalId, ProposalState)>;
+
+    /// Returns at most `limit` ids of Active proposals, starting from the `offset`-th one.
+    /// The order of active proposals changes when any of them stops being Active.
+    #[ink(message)]
+    fn active_proposal_ids(&self, offset: u32, limit: u32) -> Vec<ProposalId>;
+
+    /// Returns at most `limit` proposals created by `account` with their states in the order of creation, starting from the `offset`-th one.
+    #[ink(message)]
+    fn proposals_of_proposer(&self, account: AccountId, offset: u32, limit: u32) -> Vec<(ProposalId, ProposalState)>;
 }
 
 #[openbrush::trait_definition]
@@ -71,7 +120,10 @@ pub trait Govern {
     fn propose(&mut self, proposal: Proposal, description: String) -> Result<Hash, GovernError>;
 
     /// Finilize `proposal_id` if the finalization conditions are met.
-    /// If finalized with `Succeeded` or with `Defeated` the AZERO deposit is returned to the proposer.
+    /// Proposal can be finalized with `Succeeded`, `Defeated` or `DefeatedWithSlash` only if the quorum was reached,
+    /// so a small minority can't defeat the proposal or slash the proposer.
+    /// Proposal that didn't reach the quorum until the end of the final period is finalized with `Expired`.
+    /// If finalized with `Succeeded`, `Defeated` or `Expired` the AZERO deposit is returned to the proposer.
     /// If finalized with `DefeatedWithSlash` deposit is not returned and the `StakeInternal::_slash_stake_of` is called.   
     ///
     /// On success emits `ProposalFinalized` event.
@@ -84,19 +136,61 @@ pub trait Govern {
     #[ink(message)]
     fn finalize(&mut self, proposal_id: ProposalId) -> Result<(), GovernError>;
 
-    /// Executes the `proposal` with `describtion_hash` which corresponds to the `proposal_id` with `Succeeded` status.
+    /// Queues the `proposal_id` with `Succeeded` status for execution.
+    /// The proposal can be executed after `eta` which is current timestamp plus `rules.execution_delay`.
     ///
-    /// On success emits `ProposalExecuted` event.
+    /// On success emits `ProposalQueued` event.
     ///
     /// # Errors
     /// Returns `ProposalDoesntExist` if proposal doesn't exist.
     /// Returns `WrongStatus` if proposal status is not `Succeeded`.
-    /// Returns `UnderlyingTransactionReverted` if any of Transactions from the `proposal` fails.
+    #[ink(message)]
+    fn queue(&mut self, proposal_id: ProposalId) -> Result<(), GovernError>;
+
+    /// Executes the `proposal` with `describtion_hash` which corresponds to the `proposal_id` with `Queued` status.
+    ///
+    /// On success emits `ProposalExecuted` event.
+    ///
+    /// # Errors
+    /// Returns `ProposalDoesntExist` if proposal doesn't exist.
+    /// Returns `WrongStatus` if proposal status is not `Queued`.
+    /// Returns `TimelockNotPassed` if `eta` of the proposal hasn't passed yet.
+    /// Returns `ProposalExpired` if the execution grace period after `eta` has passed.
+    /// Returns `UnderlyingTransactionReverted` with the index of the first Transaction from the `proposal` that failed and doesn't allow failure.
     #[ink(message)]
     fn execute(&mut self, proposal: Proposal, description_hash: [u8; 32]) -> Result<(), GovernError>;
 
+    /// Executes the proposal identified by `proposal_id` with `Queued` status using the transactions stored on-chain.
+    ///
+    /// On success emits `ProposalExecuted` event.
+    ///
+    /// # Errors
+    /// Returns `ProposalDoesntExist` if proposal doesn't exist or its transactions weren't stored.
+    /// Returns `WrongStatus` if proposal status is not `Queued`.
+    /// Returns `TimelockNotPassed` if `eta` of the proposal hasn't passed yet.
+    /// Returns `ProposalExpired` if the execution grace period after `eta` has passed.
+    /// Returns `UnderlyingTransactionReverted` with the index of the first Transaction from the proposal that failed and doesn't allow failure.
+    #[ink(message)]
+    fn execute_by_id(&mut self, proposal_id: ProposalId) -> Result<(), GovernError>;
+
+    /// Cancels the `proposal_id`.
+    /// The proposer can cancel the proposal as long as no votes were cast. The deposit is returned to the proposer.
+    /// The owner can cancel the proposal at any time. The deposit is returned if `rules.owner_cancel_returns_deposit` is true.
+    ///
+    /// On success emits `ProposalCancelled` event.
+    ///
+    /// # Errors
+    /// Returns `ProposalDoesntExist` if proposal doesn't exist.
+    /// Returns `NotActive` if proposal is not active.
+    /// Returns `CancelCondition` if `caller` is the proposer and votes were already cast.
+    /// Returns wrapped `OwnableError` if `caller` is neither the proposer nor the owner.
+    /// Returns `TransferError` if transfering deposit of native currency to the proposer failed.
+    #[ink(message)]
+    fn cancel(&mut self, proposal_id: ProposalId) -> Result<(), GovernError>;
+
     /// Cast vote in the name of `caller` on `proposa_id` for `vote` with `reason`.
-    /// `reason` is not stored. Only users with active stake can vote.
+    /// `reason` is emitted and stored if the proposal rules allow to store vote reasons. Only users with active stake or with delegated voting power can vote.
+    /// The amount of votes is `caller` voting power at the proposal start, excluding changes made at the start itself (see `ProposalState::snapshot`).
     ///
     /// On Success emits `VoteCasted` event.
     ///
@@ -104,8 +198,39 @@ pub trait Govern {
     /// Returns `ZeroVotes` if `caller` has no votes.
     /// Returns `ProposalDoesntExist` if proposal doesn't exist.
     /// Returns `NotActive` if proposal status isn't `Active`.
+    /// Returns `VoteReasonTooLong` if `reason` is longer than `maximal_vote_reason_length` of the proposal rules.
     #[ink(message)]
     fn vote(&mut self, proposal_id: ProposalId, vote: Vote, reason: Vec<u8>) -> Result<(), GovernError>;
+
+    /// Cast votes in the name of `caller` on each of proposals for the paired `vote` in the same way as `vote` with no reason.
+    /// Returns the result for each of `votes` - failing votes don't revert the successful ones.
+    ///
+    /// Emits `VoteCasted` event for each successful vote.
+    #[ink(message)]
+    fn vote_batch(&mut self, votes: Vec<(ProposalId, Vote)>) -> Vec<Result<(), GovernError>>;
+
+    /// Cast vote in the name of `voter` on `proposal_id` for `vote` with the `signature` of the payload made off-chain by the `voter`.
+    /// The payload is returned by `vote_by_sig_payload`. Anyone can submit the signed vote.
+    ///
+    /// On Success emits `VoteCasted` event.
+    ///
+    /// # Errors
+    /// Returns `SignatureExpired` if the `deadline` has passed.
+    /// Returns `InvalidNonce` if `nonce` is different than `vote_nonce_of(voter)`.
+    /// Returns `InvalidSignature` if the `signature` wasn't made by the `voter`.
+    /// Returns `ZeroVotes` if `voter` has no votes.
+    /// Returns `ProposalDoesntExist` if proposal doesn't exist.
+    /// Returns `NotActive` if proposal status isn't `Active`.
+    #[ink(message)]
+    fn vote_by_sig(
+        &mut self,
+        voter: AccountId,
+        proposal_id: ProposalId,
+        vote: Vote,
+        nonce: u64,
+        deadline: Timestamp,
+        signature: VoteSignature,
+    ) -> Result<(), GovernError>;
 }
 
 #[openbrush::trait_definition]
@@ -114,7 +239,17 @@ pub trait GovernRewardableSlashable {
     #[ink(message)]
     fn claimed_or_slashed(&self, account: AccountId, proposal_id: ProposalId) -> bool;
 
-    /// Claims the reward for `account` or slashes `account` if voted for `proposal_id`.
+    /// Returns the amount that would be slashed from `account` by `slash_voter` for `proposal_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error `slash_voter` would fail with, which is the reason `account` is not slashable.
+    #[ink(message)]
+    fn is_slashable(&self, account: AccountId, proposal_id: ProposalId) -> Result<Balance, GovernError>;
+
+    /// Claims the reward for `caller` if voted for `proposal_id`.
+    /// If `caller` voted the reward is based on the part of the vote that wasn't delegated to `caller`.
+    /// If `caller` was delegating at the proposal start and the delegate voted the reward is based on `caller` stake at the proposal start.
     /// Modifies `account` stake and changes `claimed_or_slashed`.
     ///
     /// On Success emits `Claimed` event.
@@ -122,14 +257,29 @@ pub trait GovernRewardableSlashable {
     /// # Errors
     ///
     /// Returns `ProposalDoesntExist` if proposal doesn't exist.
-    /// Returns `WrongStatus` if proposal was not finalized.
+    /// Returns `WrongStatus` if proposal was not finalized or was cancelled.
     /// Returns `AlreadyClaimedOrSlashed` if reward was already claimed.
-    /// Returns `DidntVote` if `account` didn't vote for the `proposal_id`
+    /// Returns `DidntVote` if neither `account` nor its delegate at the proposal start voted for the `proposal_id`
     /// Returns `InnsuficientVotes` if `account` is unstaking.
     #[ink(message)]
     fn claim_reward(&mut self, proposal_id: ProposalId) -> Result<(), GovernError>;
 
+    /// Claims the rewards for `caller` for each of `proposal_ids` in the same way as `claim_reward`.
+    /// Returns the result for each of `proposal_ids` - failing claims don't revert the successful ones.
+    /// The rewards of successful claims are summed up and given to `caller` at once.
+    ///
+    /// On Success emits `Claimed` event for each successful claim.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MathError` if the sum of rewards overflows.
+    /// Returns `StakeError` if rewarding `caller` failed.
+    #[ink(message)]
+    fn claim_rewards(&mut self, proposal_ids: Vec<ProposalId>) -> Result<Vec<Result<(), GovernError>>, GovernError>;
+
     /// Slashes the stake of `account` if didn't vote for `proposal_id` and `proposal_id` was finalized during "final_period".
+    /// Only `account` own stake at the proposal start is taken into account - the power delegated to `account` is not slashed.
+    /// `account` that was delegating at the proposal start is slashed only if its delegate didn't vote.
     /// Modifies `account` stake and changes `claimed_or_slashed`.
     ///
     /// On Success emits `Slashed` event.
@@ -137,13 +287,84 @@ pub trait GovernRewardableSlashable {
     /// # Errors
     ///
     /// Returns `ProposalDoesntExist` if proposal doesn't exist.
-    /// Returns `WrongStatus` if proposal was not finalized.
+    /// Returns `WrongStatus` if proposal was not finalized or was cancelled.
     /// Returns `AlreadyClaimedOrSlashed` if reward was already claimed.
-    /// Returns `Voted` if `account` voted for the `proposal_id`
+    /// Returns `Voted` if `account` or its delegate at the proposal start voted for the `proposal_id`
     /// Returns `InnsuficientVotes` if `account` is unstaking.
-    /// Returns `NothingToSlash` if `account` has not stake or has staken after the `proposal_id` was finalized or `proposal_id` has finalized in "flat period".
+    /// Returns `NothingToSlash` if `account` had no stake at the proposal start, the penalty rounds down to 0, or `account` has staken during `voter_slash_grace_period` before the `proposal_id` was finalized or `proposal_id` has finalized in "flat period".
     #[ink(message)]
     fn slash_voter(&mut self, account: AccountId, proposal_id: ProposalId) -> Result<(), GovernError>;
+
+    /// Slashes each of `accounts` in the same way as `slash_voter`.
+    /// Returns the slashed amount or the error for each of `accounts` - ineligible accounts don't revert the slashing of the others.
+    /// The `slash_bounty_part_e12` of the proposal rules of the total slashed amount is transferred to `caller`.
+    ///
+    /// On Success emits `Slashed` event for each slashed account.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ProposalDoesntExist` if proposal doesn't exist.
+    /// Returns `StakeError` if transferring the bounty to `caller` failed.
+    #[ink(message)]
+    fn slash_voters(
+        &mut self,
+        proposal_id: ProposalId,
+        accounts: Vec<AccountId>,
+    ) -> Result<Vec<Result<Balance, GovernError>>, GovernError>;
+}
+
+#[openbrush::trait_definition]
+pub trait GovernDelegate {
+    /// Delegates the voting power of `caller` stake to `to`.
+    /// While delegating `caller` can not vote with its own stake, but still can vote with the power delegated to it.
+    /// Changes of `caller` stake are reflected in the power delegated to `to`.
+    ///
+    /// On Success emits `Delegated` event. If `caller` was already delegating emits `Undelegated` event first.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SelfDelegation` if `to` is `caller`.
+    #[ink(message)]
+    fn delegate(&mut self, to: AccountId) -> Result<(), GovernError>;
+
+    /// Stops delegating the voting power of `caller` stake.
+    ///
+    /// On Success emits `Undelegated` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotDelegating` if `caller` isn't delegating.
+    #[ink(message)]
+    fn undelegate(&mut self) -> Result<(), GovernError>;
+
+    /// Returns Some(delegate) if `account` is delegating and None if isn't.
+    #[ink(message)]
+    fn delegate_of(&self, account: AccountId) -> Option<AccountId>;
+
+    /// Returns the voting power currently delegated to `account`.
+    #[ink(message)]
+    fn delegated_power_of(&self, account: AccountId) -> Balance;
+}
+
+#[openbrush::trait_definition]
+pub trait GovernTreasury {
+    /// Returns the balance of the treasury - forfeited deposits and slashed stake that were not spent yet.
+    #[ink(message)]
+    fn treasury_balance(&self) -> TreasuryBalance;
+
+    /// Transfers `amount` of `asset` from the treasury to `to`.
+    /// The `owner` is the governor itself so the treasury can be spent only by an executed proposal.
+    ///
+    /// On Success emits `TreasuryWithdrawn` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InsufficientTreasuryBalance` if the treasury holds less than `amount` of `asset`.
+    /// Returns `TransferError` if the transfer of native token failed.
+    /// Returns `StakeError` if the transfer of `want` token failed.
+    /// Returns wrapped `OwnableError` if the `caller` is not the `owner`.
+    #[ink(message)]
+    fn treasury_transfer(&mut self, asset: TreasuryAsset, to: AccountId, amount: Balance) -> Result<(), GovernError>;
 }
 
 #[openbrush::trait_definition]
@@ -154,10 +375,24 @@ pub trait GovernManage {
     ///
     /// #Errors
     ///
+    /// Returns `WrongParameters` if any of `rules` parts is greater than 100%, the initial or the final period is 0 or the `finalization_curve` parameters are invalid.
     /// Returns wrapped `OwnableError` if the `caller` is not the `owner`.
     #[ink(message)]
     fn add_proposal_rules(&mut self, rules: ProposalRules) -> Result<(), GovernError>;
 
+    /// Sets `rules` as the new version of rules identified by `rules_id`.
+    /// Only proposals created afterwards use the new version.
+    ///
+    /// On Success emits `ProposalRulesUpdated` event.
+    ///
+    /// #Errors
+    ///
+    /// Returns `NoSuchRule` if there in no rule identified by `rules_id`.
+    /// Returns `WrongParameters` if any of `rules` parts is greater than 100%, the initial or the final period is 0 or the `finalization_curve` parameters are invalid.
+    /// Returns wrapped `OwnableError` if the `caller` is not the `owner`.
+    #[ink(message)]
+    fn update_rules(&mut self, rules_id: RulesId, rules: ProposalRules) -> Result<(), GovernError>;
+
     /// Allow/Disallow to use `rules` identified by `rules_id`.
     ///
     /// On Success emits `RulesAllowed` event.
@@ -197,12 +432,55 @@ pub trait GovernInternal {
         description: &String,
     ) -> Result<(), GovernError>;
 
+    /// Cast vote in the name of `voter` on `proposal_id` for `vote` with `reason`.
+    ///
+    /// On Success emits `VoteCasted` event.
+    ///
+    /// # Errors
+    /// Returns `ZeroVotes` if `voter` has no votes.
+    /// Returns `ProposalDoesntExist` if proposal doesn't exist.
+    /// Returns `NotActive` if proposal status isn't `Active`.
+    /// Returns `VoteReasonTooLong` if `reason` is longer than `maximal_vote_reason_length` of the proposal rules.
+    fn _cast_vote(
+        &mut self,
+        voter: &AccountId,
+        proposal_id: &ProposalId,
+        vote: &Vote,
+        reason: &Vec<u8>,
+    ) -> Result<(), GovernError>;
+
+    /// Returns the domain separated SCALE encoded payload of the vote to be signed by `voter`.
+    fn _vote_by_sig_payload(
+        &self,
+        voter: &AccountId,
+        proposal_id: &ProposalId,
+        vote: &Vote,
+        nonce: u64,
+        deadline: Timestamp,
+    ) -> Vec<u8>;
+
+    /// Verifies that `signature` of `payload` was made by `voter`.
+    ///
+    /// # Errors
+    /// Returns `InvalidSignature` if the verification fails.
+    fn _verify_vote_signature(
+        &self,
+        voter: &AccountId,
+        payload: &[u8],
+        signature: &VoteSignature,
+    ) -> Result<(), GovernError>;
+
     /// Returns the amount of `account` votes held at `timestamp`.
+    /// It is the `account` stake (if `account` wasn't delegating) plus the power delegated to `account` at `timestamp`.
     fn _get_votes_at(&self, account: &AccountId, timestamp: &Timestamp) -> Balance;
 
-    /// Returns the minimal amount of votes to finalize proposal with `state` that uses `rules` at time `now`.    
+    /// Returns the minimal amount of votes to finalize proposal with `state` that uses `rules` at time `now`.
+    /// The amount depends on the `rules.finalization_curve`.
     fn _minimum_to_finalize(&self, state: &ProposalState, rules: &ProposalRules, now: Timestamp) -> Balance;
 
+    /// Returns the minimal amount of votes that has to be casted on proposal with `state` that uses `rules` to succeed.
+    fn _quorum(&self, state: &ProposalState, rules: &ProposalRules) -> Balance;
+
     /// Finalizes proposal identified by `proposal_id`
     ///
     /// On success emits `ProposalFinalized` event.
@@ -214,17 +492,127 @@ pub trait GovernInternal {
     /// Returns `TransferError` if proposal was finalized with `Succeeded`, `Defeated` and transfering  deposit of native currency to the proposer failed.
     fn _finalize(&mut self, proposal_id: &ProposalId) -> Result<(), GovernError>;
 
-    /// Executes the `proposal`
+    /// Cancels proposal identified by `proposal_id` in the name of `caller`.
     ///
-    /// On success emits `ProposalExecuted` event.
+    /// On success emits `ProposalCancelled` event.
     ///
     /// # Errors
     /// Returns `ProposalDoesntExist` if there is no proposal identified by `proposal_id.
-    /// Returns `WronfStatus` if proposal identified by `proposal_id` has different than Succeeded status.
-    /// Returns `UnderlyingTransactionReverted` if any of Transactions from the `proposal` fails.
+    /// Returns `NotActive` if proposal identified by `proposal_id` isnt Active.
+    /// Returns `CancelCondition` if `caller` is the proposer and votes were already cast.
+    /// Returns wrapped `OwnableError` if `caller` is neither the proposer nor the owner.
+    /// Returns `TransferError` if transfering deposit of native currency to the proposer failed.
+    fn _cancel(&mut self, proposal_id: &ProposalId) -> Result<(), GovernError>;
+
+    /// Queues proposal identified by `proposal_id` for execution.
+    ///
+    /// On success emits `ProposalQueued` event.
+    ///
+    /// # Errors
+    /// Returns `ProposalDoesntExist` if there is no proposal identified by `proposal_id.
+    /// Returns `WrongStatus` if proposal identified by `proposal_id` has different than Succeeded status.
+    fn _queue(&mut self, proposal_id: &ProposalId) -> Result<(), GovernError>;
+
+    /// Returns true if `state` is `Queued` and the execution grace period has passed at `now`
+    /// or if `state` is `Active`, the final period has passed at `now` and the quorum wasn't reached.
+    fn _is_expired(&self, state: &ProposalState, now: Timestamp) -> bool;
+
+    /// Executes the `proposal`. Each Transaction is called with its `gas_limit` and its outcome is recorded.
+    ///
+    /// On success emits `ProposalExecuted` event with outcomes of all Transactions.
+    ///
+    /// # Errors
+    /// Returns `ProposalDoesntExist` if there is no proposal identified by `proposal_id.
+    /// Returns `WronfStatus` if proposal identified by `proposal_id` has different than Queued status.
+    /// Returns `TimelockNotPassed` if `eta` of the proposal hasn't passed yet.
+    /// Returns `ProposalExpired` if the execution grace period after `eta` has passed.
+    /// Returns `UnderlyingTransactionReverted` with the index of the first Transaction from the `proposal` that failed and doesn't allow failure.
     fn _execute(&mut self, proposal_id: &ProposalId, proposal: &Proposal) -> Result<(), GovernError>;
 }
 
+/// Extension points of the proposal lifecycle. Every method is a no-op by default.
+/// A contract embedding `Govern` can implement this trait to add custom eligibility checks and side effects.
+/// Returning an error from a hook reverts the whole call.
+pub trait GovernHooks {
+    /// Called before the `proposal` identified by `proposal_id` is registered with `proposer` as its proposer.
+    fn _before_propose(
+        &mut self,
+        proposer: &AccountId,
+        proposal_id: &ProposalId,
+        proposal: &Proposal,
+    ) -> Result<(), GovernError>;
+
+    /// Called after `voter` has casted `vote` with `amount` of votes for `proposal_id`.
+    fn _after_vote(
+        &mut self,
+        voter: &AccountId,
+        proposal_id: &ProposalId,
+        vote: &Vote,
+        amount: &Balance,
+    ) -> Result<(), GovernError>;
+
+    /// Called before the Active proposal described by `state` and identified by `proposal_id` is finalized.
+    fn _before_finalize(&mut self, proposal_id: &ProposalId, state: &ProposalState) -> Result<(), GovernError>;
+
+    /// Called after the proposal identified by `proposal_id` was finalized with `status`.
+    fn _after_finalize(&mut self, proposal_id: &ProposalId, status: &ProposalStatus) -> Result<(), GovernError>;
+
+    /// Called before the transactions of `proposal` identified by `proposal_id` are executed.
+    fn _before_execute(&mut self, proposal_id: &ProposalId, proposal: &Proposal) -> Result<(), GovernError>;
+}
+
+pub trait GovernTreasuryInternal {
+    /// Notes that `amount` of `asset` held by the contract belongs to the treasury.
+    ///
+    /// On Success emits `TreasuryDeposited` event if `amount` is not 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MathError` if the treasury balance overflows.
+    fn _deposit_to_treasury(&mut self, asset: &TreasuryAsset, amount: &Balance) -> Result<(), GovernError>;
+
+    /// Notes that `amount` of `asset` held by the contract no longer belongs to the treasury, for example because it funds a vesting.
+    ///
+    /// On Success emits `TreasuryWithdrawn` event to the contract itself if `amount` is not 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InsufficientTreasuryBalance` if the treasury holds less than `amount` of `asset`.
+    fn _take_from_treasury(&mut self, asset: &TreasuryAsset, amount: &Balance) -> Result<(), GovernError>;
+
+    /// Transfers `amount` of `asset` from the treasury to `to`.
+    ///
+    /// On Success emits `TreasuryWithdrawn` event if `amount` is not 0.
+    ///
+    /// # Errors
+    ///
+    /// Same as `treasury_transfer` except `OwnableError`.
+    fn _withdraw_from_treasury(
+        &mut self,
+        asset: &TreasuryAsset,
+        to: &AccountId,
+        amount: &Balance,
+    ) -> Result<(), GovernError>;
+}
+
+pub trait GovernDelegateInternal {
+    /// Sets the delegate of `account` to `delegate`, moving `account` stake from the old delegate power to the new one.
+    ///
+    /// On Success emits `Undelegated` event if `account` was delegating and `Delegated` event if `delegate` is Some.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SelfDelegation` if `delegate` is `account`.
+    fn _set_delegate(&mut self, account: &AccountId, delegate: &Option<AccountId>) -> Result<(), GovernError>;
+
+    /// Writes the checkpoint of `account` stake and moves the change of `account` stake to the power of its delegate.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MathError` if the power of `account` delegate overflows or underflows.
+    fn _checkpoint_stake_and_delegated_power_of(&mut self, account: &AccountId) -> Result<(), StakeError>;
+}
+
 pub trait GovernRewardableSlashableInternal {
     fn _claimed_or_slashed(&self, account: &AccountId, proposal_id: &ProposalId) -> bool;
 
@@ -236,24 +624,67 @@ pub trait GovernRewardableSlashableInternal {
     /// # Errors
     ///
     /// Returns `ProposalDoesntExist` if proposal doesn't exist.
-    /// Returns `WrongStatus` if proposal was not finalized.
+    /// Returns `WrongStatus` if proposal was not finalized or was cancelled.
     /// Returns `AlreadyClaimedOrSlashed` if reward was already claimed.
-    /// Returns `DidntVote` if `account` didn't vote for the `proposal_id`
+    /// Returns `DidntVote` if neither `account` nor its delegate at the proposal start voted for the `proposal_id`
     /// Returns `InnsuficientVotes` if `account` is unstaking.
     fn _reward_voter(&mut self, account: &AccountId, proposal_id: &ProposalId) -> Result<(), GovernError>;
 
+    /// Claims the rewards for `account` for each of `proposal_ids` with a single `_reward` call.
+    /// Returns the result for each of `proposal_ids`.
+    ///
+    /// On Success emits `Claimed` event for each successful claim.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MathError` if the sum of rewards overflows.
+    /// Returns `StakeError` if rewarding `account` failed.
+    fn _reward_voter_for_many(
+        &mut self,
+        account: &AccountId,
+        proposal_ids: &Vec<ProposalId>,
+    ) -> Result<Vec<Result<(), GovernError>>, GovernError>;
+
+    /// Returns the reward of `account` for voting for `proposal_id`.
+    ///
+    /// # Errors
+    ///
+    /// Same as `_reward_voter`.
+    fn _voter_reward_of(&self, account: &AccountId, proposal_id: &ProposalId) -> Result<Balance, GovernError>;
+
     /// Slashes the stake of `account` if didn't vote for `proposal_id` and `proposal_id` was finalized during "final_period".
-    /// Modifies `account` stake and changes `claimed_or_slashed`.
+    /// Modifies `account` stake and changes `claimed_or_slashed`. Returns the slashed amount.
     ///
     /// On Success emits `Slashed` event.
     ///
     /// # Errors
     ///
     /// Returns `ProposalDoesntExist` if proposal doesn't exist.
-    /// Returns `WrongStatus` if proposal was not finalized.
+    /// Returns `WrongStatus` if proposal was not finalized or was cancelled.
     /// Returns `AlreadyClaimedOrSlashed` if reward was already claimed.
-    /// Returns `Voted` if `account` voted for the `proposal_id`
+    /// Returns `Voted` if `account` or its delegate at the proposal start voted for the `proposal_id`
     /// Returns `InnsuficientVotes` if `account` is unstaking.
-    /// Returns `NothingToSlash` if `account` has not stake or has staken after the `proposal_id` was finalized or `proposal_id` has finalized in "flat period".
-    fn _slash_voter(&mut self, account: &AccountId, proposal_id: &ProposalId) -> Result<(), GovernError>;
+    /// Returns `NothingToSlash` if `account` had no stake at the proposal start, the penalty rounds down to 0, or `account` has staken during `voter_slash_grace_period` before the `proposal_id` was finalized or `proposal_id` has finalized in "flat period".
+    fn _slash_voter(&mut self, account: &AccountId, proposal_id: &ProposalId) -> Result<Balance, GovernError>;
+
+    /// Returns the amount to be slashed from `account` for not voting for `proposal_id`.
+    ///
+    /// # Errors
+    ///
+    /// Same as `_slash_voter`.
+    fn _voter_penalty_of(&self, account: &AccountId, proposal_id: &ProposalId) -> Result<Balance, GovernError>;
+
+    /// Slashes each of `accounts` with `_slash_voter` and transfers the `slash_bounty_part_e12` of the total slashed amount to `keeper`.
+    /// Returns the slashed amount or the error for each of `accounts`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ProposalDoesntExist` if proposal doesn't exist.
+    /// Returns `StakeError` if transferring the bounty to `keeper` failed.
+    fn _slash_voters(
+        &mut self,
+        keeper: &AccountId,
+        proposal_id: &ProposalId,
+        accounts: &Vec<AccountId>,
+    ) -> Result<Vec<Result<Balance, GovernError>>, GovernError>;
 }
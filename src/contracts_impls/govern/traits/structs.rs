@@ -1,4 +1,7 @@
-use ink::prelude::vec::*;
+use ink::{
+    prelude::vec::*,
+    LangError,
+};
 use openbrush::traits::{
     AccountId,
     Balance,
@@ -11,6 +14,25 @@ use scale::{
 
 use super::RulesId;
 
+#[derive(Debug, Clone, Copy, PartialEq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+/// The curve of the minimal amount of votes required to finalize the proposal over time. Every curve falls to 0 after the final period.
+/// Unless stated otherwise the same minimum applies to the votes for (to succeed) and to the votes against (to defeat).
+pub enum FinalizationCurve {
+    /// falls linearly from 100% to 50% of total votes during the initial period, flat 50% during the flat period,
+    /// falls linearly from 50% to 0 during the final period.
+    #[default]
+    Linear3Phase,
+    /// flat part of total votes during the whole voting. 10^12 is 100%.
+    FixedMajority(u64),
+    /// flat part of total votes during the whole voting, the same minimum to succeed as `FixedMajority`. 10^12 is 100%.
+    /// Differs only in the minimum to defeat, which is the rest of total votes plus one,
+    /// so the proposal is defeated as soon as the part can't be reached by the votes for.
+    Supermajority(u64),
+    /// falls from 100% of total votes by half every `half_life`, linearly between the halvings.
+    ExponentialDecay(Timestamp),
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Encode, Decode, Default)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
 
@@ -25,12 +47,30 @@ pub struct ProposalRules {
     pub flat_period: Timestamp,
     /// time after flat_period during which the required amount to finalize proposal linearly falls to 0.
     pub final_period: Timestamp,
+    /// the curve of the required amount to finalize proposal. The periods above describe `Linear3Phase` and the total length of voting for other curves.
+    pub finalization_curve: FinalizationCurve,
     /// maximal possible reward for voters who vote for the proposal. 10^12 is 100% increased stake,
     pub maximal_voter_reward_part_e12: u64,
     /// the part of proposer stake to be slashed if proposal finishes with `DefeatedWithSlash`. 10^12 is 100% slashed stake.
     pub proposer_slash_part_e12: u64,
     /// the part of voter stake to be slashed if voter didn't vote for the proposal and the proposal was finalized after linear period. 10^12 is 100%.
     pub voter_slash_part_e12: u64,
+    /// time before the proposal finalization during which the stakers are not slashed for not voting.
+    pub voter_slash_grace_period: Timestamp,
+    /// the part of the amount slashed with `slash_voters` that is paid to the caller. 10^12 is 100%.
+    pub slash_bounty_part_e12: u64,
+    /// minimal part of total votes that has to be casted for the proposal to succeed. 10^12 is 100%.
+    pub quorum_part_e12: u64,
+    /// if true the deposit is returned to the proposer when the proposal is cancelled by the owner. Otherwise it stays with the contract.
+    pub owner_cancel_returns_deposit: bool,
+    /// time that must pass between queueing the `Succeeded` proposal and its execution.
+    pub execution_delay: Timestamp,
+    /// time after the proposal `eta` during which the proposal can be executed. After it the proposal is `Expired`.
+    pub execution_grace_period: Timestamp,
+    /// maximal length in bytes of the reason passed with the vote.
+    pub maximal_vote_reason_length: u32,
+    /// if true the reasons passed with the votes are stored on-chain. Otherwise they are only emitted in `VoteCasted` event.
+    pub store_vote_reasons: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Encode, Decode)]
@@ -44,6 +84,24 @@ pub struct Transaction {
     pub input: Vec<u8>,
     /// The amount of chain balance that is transferred to the callee.
     pub transferred_value: Balance,
+    /// The gas limit of the call. None means that all the remaining gas can be used.
+    pub gas_limit: Option<u64>,
+    /// If true the failure of this transaction doesn't revert the execution of the proposal.
+    pub allow_failure: bool,
+}
+
+/// The outcome of the executed `Transaction`.
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum TransactionOutcome {
+    /// The call succeeded. Contains the SCALE encoded value returned by the callee.
+    /// ink! decodes the output of a message that returned an `Err` (and so reverted its changes) like a regular return value,
+    /// so such a call is reported here with the encoded `Err`.
+    Succeeded(Vec<u8>),
+    /// The call failed with `LangError`, for example the selector was not found.
+    LangError(LangError),
+    /// The callee reverted or trapped, or there is no contract to call at `callee`.
+    Reverted,
 }
 
 /// A Proposal is what can be proposed
@@ -67,10 +125,16 @@ pub enum ProposalStatus {
     Defeated,
     /// Rejected by DAO. Proposer was slashed.
     DefeatedWithSlash,
-    /// Accepted by DAO. Ready for execution.
+    /// Accepted by DAO. Ready to be queued.
     Succeeded,
+    /// Queued for execution. Can be executed after `eta`.
+    Queued,
     /// Executed
     Executed,
+    /// Queued, but not executed during the execution grace period or didn't reach the quorum until the end of the final period.
+    Expired,
+    /// Cancelled by the proposer or the owner.
+    Cancelled,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Encode, Decode)]
@@ -84,6 +148,8 @@ pub struct ProposalState {
     pub voter_reward_part_e12: u64,
     /// rules_id
     pub rules_id: RulesId,
+    /// version of rules identified by `rules_id` that were current at the proposal creation and apply to the proposal.
+    pub rules_version: u32,
     /// time of proposition
     pub start: Timestamp,
     /// Stake::total_stake at start
@@ -92,12 +158,34 @@ pub struct ProposalState {
     pub counter_at_start: Balance,
     /// time of proposal finalization. Some if proposal finalized. None if porposal is not finalized yet.
     pub finalized: Option<Timestamp>,
+    /// time after which the queued proposal can be executed. Some if proposal was queued. None if proposal is not queued yet.
+    pub eta: Option<Timestamp>,
     /// amount of votes to accept the proposal
     pub votes_for: Balance,
     /// amount of votes to reject proposal
     pub votes_against: Balance,
     /// amount of votes to reject proposal and slash the proposer
     pub votes_against_with_slash: Balance,
+    /// amount of votes that abstained
+    pub votes_abstain: Balance,
+}
+
+impl ProposalState {
+    /// Timestamp at which the votes are taken - the last moment before `start`.
+    /// Stake and delegations changed at `start`, even in the same block before the proposal was made, don't count.
+    pub fn snapshot(&self) -> Timestamp {
+        self.start.saturating_sub(1)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+/// Signature of the vote payload made by the voter off-chain.
+pub enum VoteSignature {
+    /// ecdsa signature of the blake2x256 hash of the payload. The voter `AccountId` is the blake2x256 hash of the compressed public key.
+    Ecdsa([u8; 65]),
+    /// sr25519 signature of the payload. The voter `AccountId` is the public key.
+    Sr25519([u8; 64]),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Encode, Decode)]
@@ -110,6 +198,27 @@ pub enum Vote {
     Disagreed,
     /// Disagree and slash the proposal. Should be chosen if the proposition is made to hurt the DAO.
     DisagreedWithProposerSlashing,
+    /// Abstain. Counts toward the quorum, but neither for nor against the proposal.
+    Abstained,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+/// Assets held by the treasury
+pub enum TreasuryAsset {
+    /// native token of the chain - comes from forfeited deposits.
+    Native,
+    /// `want` token - comes from slashed stake.
+    Want,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+pub struct TreasuryBalance {
+    /// amount of native token held by the treasury.
+    pub native: Balance,
+    /// amount of `want` token held by the treasury.
+    pub want: Balance,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Encode, Decode)]
@@ -120,3 +229,13 @@ pub struct UserVote {
     /// amount of votes
     pub amount: Balance,
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+/// Represents the delegate chosen by an account that was valid from `timestamp` until the next checkpoint.
+pub struct DelegateCheckpoint {
+    /// timestamp from which the checkpoint is valid.
+    pub timestamp: Timestamp,
+    /// chosen delegate. None if account was not delegating.
+    pub delegate: Option<AccountId>,
+}
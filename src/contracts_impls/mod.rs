@@ -1,3 +1,5 @@
 pub mod govern;
 pub mod stake;
 pub mod timestamp_mock;
+pub mod treasury;
+pub mod vesting;
@@ -17,7 +17,7 @@ use ink::prelude::{
 
 use openbrush::{
     contracts::{
-        ownable::*,
+        access_control,
         psp22::extensions::{
             burnable::*,
             mintable::*,
@@ -27,6 +27,7 @@ use openbrush::{
 };
 
 use self::storage::data::{
+    StakeCheckpointsStorage,
     StakeCounterStorage,
     StakeStorage,
     StakeTimesStorage,
@@ -43,6 +44,10 @@ pub trait StakeViewImpl: Storage<StakeStorage> + StakeInternal + StakeTransfer {
         self.data::<StakeStorage>().unstake_period
     }
 
+    fn minimal_unstake_period(&self) -> Timestamp {
+        self.data::<StakeStorage>().minimal_unstake_period
+    }
+
     fn maximal_number_of_unstakes(&self) -> u64 {
         self.data::<StakeStorage>().maximal_number_of_unstakes
     }
@@ -73,6 +78,7 @@ pub trait StakeImpl:
     + EmitStakeEvents
     + Storage<StakeTimesStorage>
     + Storage<StakeCounterStorage>
+    + StakeCheckpointsInternal
     + Storage<TimestampMockStorage>
     + TimestampMockInternal
 {
@@ -85,9 +91,11 @@ pub trait StakeImpl:
         self._transfer_in(&caller, &amount)?;
         self.data::<StakeTimesStorage>()
             .update_stake_timestamps_of(&caller, &timestamp);
+        self._backfill_stake_checkpoint_of(&caller);
         self.data::<StakeStorage>().increase_stake_of(&caller, &amount)?;
         self.data::<StakeStorage>().increase_total_stake(&amount)?;
         self.data::<StakeCounterStorage>().increase_counter(&amount);
+        self._checkpoint_stake_of(&caller)?;
 
         self._emit_staked_event(&caller, &amount);
         Ok(())
@@ -99,12 +107,14 @@ pub trait StakeImpl:
         }
         let caller = Self::env().caller();
 
+        self._backfill_stake_checkpoint_of(&caller);
         let stake_is_zero = self.data::<StakeStorage>().decrease_stake_of(&caller, &amount)?;
         self.data::<StakeStorage>().decrease_total_stake(&amount)?;
 
         if stake_is_zero {
             self.data::<StakeTimesStorage>().remove_stake_timestamps_of(&caller);
         }
+        self._checkpoint_stake_of(&caller)?;
         let timestamp = self._timestamp();
         self.data::<StakeStorage>()
             .register_unstake(&caller, &amount, &timestamp)?;
@@ -115,17 +125,62 @@ pub trait StakeImpl:
 
     fn unstake(&mut self) -> Result<Balance, StakeError> {
         let caller = Self::env().caller();
+        self._unstake(&caller, &caller, &Balance::MAX)
+    }
 
-        let timestamp = self._timestamp();
+    /// # Storage modifications
+    /// [StakeStorage]
+    /// `unstakes_queue` of keys `(caller, unstakes_id.0..unstakes_id.1)` ready for unstaking are removed up to summed `max_amount`. The last one may be only decreased.
+    /// `total_unstake` decreased by the unstaked amount.
+    fn unstake_to(&mut self, recipient: AccountId, max_amount: Balance) -> Result<Balance, StakeError> {
+        if max_amount == 0 {
+            return Err(StakeError::AmountIsZero)
+        }
+        let caller = Self::env().caller();
+        self._unstake(&caller, &recipient, &max_amount)
+    }
 
-        let amount = self
-            .data::<StakeStorage>()
-            .deregister_ready_unstakes(&caller, &timestamp)?;
+    /// # Storage modifications
+    /// [StakeStorage]
+    /// `unstakes_queue` of keys `(caller, unstakes_id.0..unstakes_id.1)` has the entry at `index` removed.
+    /// `total_unstake` decreased by its amount.
+    /// `stakes` of key `caller` and `total_stake` increased by its amount.
+    /// [StakeTimesStorage]
+    /// `stakes_timestamps` of key `caller` set to `block_timestamp` if None.
+    /// `last_stakes_timestamps` of key `caller` set to `block_timestamp`.
+    /// [StakeCounterStorage]
+    /// `counter_stake` increased by its amount.
+    /// [StakeCheckpointsStorage]
+    /// checkpoint with new `stakes` of key `caller` is written at `block_timestamp`.
+    fn cancel_unstake(&mut self, index: u32) -> Result<Balance, StakeError> {
+        let caller = Self::env().caller();
 
-        self._transfer_out(&caller, &amount)?;
+        let amount = self.data::<StakeStorage>().deregister_unstake(&caller, &index)?;
+        self._restake(&caller, &amount)?;
 
-        self._emit_unstake_event(&caller);
+        self._emit_unstake_cancelled_event(&caller, &amount);
+        Ok(amount)
+    }
+
+    /// # Storage modifications
+    /// [StakeStorage]
+    /// `unstakes_queue` of keys `(caller, unstakes_id.0..unstakes_id.1)` removed.
+    /// `total_unstake` decreased by their summed amount.
+    /// `stakes` of key `caller` and `total_stake` increased by their summed amount.
+    /// [StakeTimesStorage]
+    /// `stakes_timestamps` of key `caller` set to `block_timestamp` if None.
+    /// `last_stakes_timestamps` of key `caller` set to `block_timestamp`.
+    /// [StakeCounterStorage]
+    /// `counter_stake` increased by their summed amount.
+    /// [StakeCheckpointsStorage]
+    /// checkpoint with new `stakes` of key `caller` is written at `block_timestamp`.
+    fn restake_all_unstakes(&mut self) -> Result<Balance, StakeError> {
+        let caller = Self::env().caller();
+
+        let amount = self.data::<StakeStorage>().deregister_all_unstakes(&caller)?;
+        self._restake(&caller, &amount)?;
 
+        self._emit_unstake_cancelled_event(&caller, &amount);
         Ok(amount)
     }
 }
@@ -146,13 +201,21 @@ pub trait StakeTimesImpl: Storage<StakeTimesStorage> {
     }
 }
 
-pub trait StakeManageImpl: Storage<StakeStorage> + Storage<ownable::Data> + StakeInternal + EmitStakeEvents {
+pub trait StakeCheckpointsImpl: Storage<StakeCheckpointsStorage> + StakeCheckpointsInternal {
+    fn stake_at(&self, account: AccountId, timestamp: Timestamp) -> Balance {
+        self._stake_at(&account, &timestamp)
+    }
+}
+
+pub trait StakeManageImpl: Storage<StakeStorage> + access_control::Internal + StakeInternal + EmitStakeEvents {
     /// # Storage modifications
     /// [StakeStorage]
     /// `unstake_period` set to `unstake_period`
-
-    // #[modifiers(only_owner())]
     fn change_unstake_period(&mut self, unstake_period: Timestamp) -> Result<(), StakeError> {
+        self._check_role(STAKE_ADMIN, Some(Self::env().caller()))?;
+        if unstake_period < self.data::<StakeStorage>().minimal_unstake_period {
+            return Err(StakeError::WrongParameters)
+        }
         self.data::<StakeStorage>().change_unstake_period(&unstake_period);
         self._emit_unstake_period_changed_event(&unstake_period);
         Ok(())
@@ -161,9 +224,11 @@ pub trait StakeManageImpl: Storage<StakeStorage> + Storage<ownable::Data> + Stak
     /// # Storage modifications
     /// [StakeStorage]
     /// `maximal_number_of_unstakes` set to `maximal_number_of_unstakes`
-
-    // #[modifiers(only_owner())]
     fn change_maximal_number_of_unstakes(&mut self, maximal_number_of_unstakes: u64) -> Result<(), StakeError> {
+        self._check_role(STAKE_ADMIN, Some(Self::env().caller()))?;
+        if maximal_number_of_unstakes == 0 || maximal_number_of_unstakes > MAXIMAL_NUMBER_OF_UNSTAKES_LIMIT {
+            return Err(StakeError::WrongParameters)
+        }
         self.data::<StakeStorage>()
             .change_maximal_number_of_unstakes(&maximal_number_of_unstakes);
         self._emit_maximal_number_of_unstakes_changed_event(&maximal_number_of_unstakes);
@@ -203,7 +268,7 @@ pub trait StakeSlashableImpl:
     /// [StakeStorage]
     /// `stakes` of key `caller` decreased by max(`amount`,`stakes` of key `caller`). Ig becomes 0 then remove.
     /// `total_stake` decreased by  max max(`amount`,`stakes` of key `caller`).
-    /// `unstakes` of keys `(caller, unstakes_id.0..unstakes_id.1)` field amount decreased appropriately to cover rest of max(`amount` - `stakes`, 0) of slash.
+    /// `unstakes_queue` of keys `(caller, unstakes_id.0..unstakes_id.1)` field amount decreased appropriately to cover rest of max(`amount` - `stakes`, 0) of slash.
     /// `total_unstake` decreased by appropriately
     /// [StakeTimesStorage]
     /// `stakes_timestamps` of key `caller` removed if `stakes` of key `caller` was removed.
@@ -220,22 +285,73 @@ impl<
         T: Storage<StakeStorage>
             + Storage<StakeCounterStorage>
             + Storage<StakeTimesStorage>
+            + StakeCheckpointsInternal
             + Storage<TimestampMockStorage>
             + TimestampMockInternal
+            + StakeTransfer
             + EmitStakeEvents,
     > StakeInternal for T
 {
+    /// # Storage modifications
+    /// [StakeStorage]
+    /// `unstakes_queue` of keys `(caller, unstakes_id.0..unstakes_id.1)` ready for unstaking are removed up to summed `max_amount`. The last one may be only decreased.
+    /// `total_unstake` decreased by the unstaked amount.
+    fn _unstake(
+        &mut self,
+        caller: &AccountId,
+        recipient: &AccountId,
+        max_amount: &Balance,
+    ) -> Result<Balance, StakeError> {
+        let timestamp = self._timestamp();
+
+        let (amount, unstakes, remaining_unstakes) = self
+            .data::<StakeStorage>()
+            .deregister_ready_unstakes(caller, &timestamp, max_amount)?;
+
+        self._transfer_out(recipient, &amount)?;
+
+        self._emit_unstake_event(caller, recipient, &amount, &unstakes, &remaining_unstakes);
+
+        Ok(amount)
+    }
+
+    /// # Storage modifications
+    /// [StakeStorage]
+    /// `stakes` of key `account` and `total_stake` increased by `amount`.
+    /// [StakeTimesStorage]
+    /// `stakes_timestamps` of key `account` set to `block_timestamp` if None.
+    /// `last_stakes_timestamps` of key `account` set to `block_timestamp`.
+    /// [StakeCounterStorage]
+    /// `counter_stake` increased by `amount`.
+    /// [StakeCheckpointsStorage]
+    /// checkpoint with new `stakes` of key `account` is written at `block_timestamp`.
+    fn _restake(&mut self, account: &AccountId, amount: &Balance) -> Result<(), StakeError> {
+        let timestamp = self._timestamp();
+        self.data::<StakeTimesStorage>()
+            .update_stake_timestamps_of(account, &timestamp);
+        self._backfill_stake_checkpoint_of(account);
+        self.data::<StakeStorage>().increase_stake_of(account, amount)?;
+        self.data::<StakeStorage>().increase_total_stake(amount)?;
+        self.data::<StakeCounterStorage>().increase_counter(amount);
+        self._checkpoint_stake_of(account)?;
+        Ok(())
+    }
+
     /// # Storage modifications
     /// [StakeStorage]
     /// `stake` of key `account` increased by `amount`.
     /// `total_stake` increased by `amount`.
     /// [StakeCounterStorage]
     /// `counter_stake` increased by `amount`.
+    /// [StakeCheckpointsStorage]
+    /// checkpoint with new `stake` of key `account` is written at `block_timestamp`.
     fn _reward(&mut self, account: &AccountId, amount: &Balance) -> Result<(), StakeError> {
+        self._backfill_stake_checkpoint_of(account);
         self.data::<StakeStorage>().increase_stake_of(&account, &amount)?;
         self.data::<StakeStorage>().increase_total_stake(&amount)?;
 
         self.data::<StakeCounterStorage>().increase_counter(&amount);
+        self._checkpoint_stake_of(&account)?;
         self._on_reward(&amount)?;
         self._emit_rewarded_event(&account, &amount);
         Ok(())
@@ -245,16 +361,19 @@ impl<
     /// [StakeStorage]
     /// `stakes` of key `caller` decreased by max(`amount`,`stakes` of key `caller`). Ig becomes 0 then remove.
     /// `total_stake` decreased by max max(`amount`,`stakes` of key `caller`).
-    /// `unstakes` of keys `(caller, unstakes_id.0..unstakes_id.1)` field amount decreased appropriately to cover rest of max(`amount` - `stakes`, 0) of slash.
+    /// `unstakes_queue` of keys `(caller, unstakes_id.0..unstakes_id.1)` field amount decreased appropriately to cover rest of max(`amount` - `stakes`, 0) of slash.
     /// `total_unstake` decreased by appropriately
     /// [StakeTimesStorage]
     /// `stakes_timestamps` of key `caller` removed if `stakes` of key `caller` was removed.
     /// `last_stakes_timestamps` oof key `caller` removed if `stakes` of key `caller` was removed.
+    /// [StakeCheckpointsStorage]
+    /// checkpoint with new `stake` of key `caller` is written at `block_timestamp` if `stakes` was decreased.
     fn _slash(&mut self, account: &AccountId, amount: &Balance) -> Result<Balance, StakeError> {
         ink::env::debug_println!("amount to slash: {}", amount);
         let stake = self.data::<StakeStorage>().stake_of(&account);
         ink::env::debug_println!("stake of: {}", stake);
         ink::env::debug_println!("total stake: {}", self.data::<StakeStorage>().total_stake);
+        self._backfill_stake_checkpoint_of(account);
         if stake >= *amount {
             let stake_is_zero = self.data::<StakeStorage>().decrease_stake_of(account, amount)?;
             ink::env::debug_println!("m1");
@@ -263,6 +382,7 @@ impl<
             if stake_is_zero {
                 self.data::<StakeTimesStorage>().remove_stake_timestamps_of(&account);
             }
+            self._checkpoint_stake_of(&account)?;
             self._emit_slashed_event(&account, &(amount));
             return Ok(*amount)
         } else {
@@ -270,10 +390,11 @@ impl<
                 self.data::<StakeStorage>().decrease_stake_of(&account, &stake)?;
                 self.data::<StakeStorage>().decrease_total_stake(&stake)?;
                 self.data::<StakeTimesStorage>().remove_stake_timestamps_of(&account);
+                self._checkpoint_stake_of(&account)?;
             }
             let unstake_amount_slashed = self
                 .data::<StakeStorage>()
-                .decrease_unstakes_of(&account, &(amount - stake));
+                .decrease_unstakes_of(&account, &(amount - stake))?;
             self.data::<StakeStorage>()
                 .decrease_total_unstake(&unstake_amount_slashed)?;
 
@@ -287,7 +408,38 @@ impl<
     }
 }
 
-impl<T: Storage<StakeStorage> + StakeInternal> StakeTransfer for T {
+impl<T: Storage<StakeStorage> + Storage<StakeCheckpointsStorage> + TimestampMockInternal> StakeCheckpointsInternal
+    for T
+{
+    /// # Storage modifications
+    /// [StakeCheckpointsStorage]
+    /// checkpoint with `stakes` of key `account` is written at `block_timestamp`.
+    default fn _checkpoint_stake_of(&mut self, account: &AccountId) -> Result<(), StakeError> {
+        let timestamp = self._timestamp();
+        let stake = self.data::<StakeStorage>().stake_of(account);
+        self.data::<StakeCheckpointsStorage>()
+            .write_checkpoint(account, &timestamp, &stake);
+        Ok(())
+    }
+
+    /// # Storage modifications
+    /// [StakeCheckpointsStorage]
+    /// checkpoint with `stakes` of key `account` is written at timestamp 0 if `account` has no checkpoints.
+    default fn _backfill_stake_checkpoint_of(&mut self, account: &AccountId) {
+        let stake = self.data::<StakeStorage>().stake_of(account);
+        self.data::<StakeCheckpointsStorage>()
+            .backfill_checkpoint(account, &stake);
+    }
+
+    default fn _stake_at(&self, account: &AccountId, timestamp: &Timestamp) -> Balance {
+        if self.data::<StakeCheckpointsStorage>().number_of_checkpoints_of(account) == 0 {
+            return self.data::<StakeStorage>().stake_of(account)
+        }
+        self.data::<StakeCheckpointsStorage>().stake_at(account, timestamp)
+    }
+}
+
+impl<T: Storage<StakeStorage>> StakeTransfer for T {
     fn _transfer_in(&self, account: &AccountId, amount: &Balance) -> Result<(), StakeError> {
         PSP22Ref::transfer_from_builder(
             &self.data::<StakeStorage>().want,
@@ -15,6 +15,7 @@ pub struct StakeStorage {
     // immuatables
     pub want: AccountId,
     // parameters
+    pub minimal_unstake_period: Timestamp,
     pub unstake_period: Timestamp,
     pub maximal_number_of_unstakes: u64,
     // data
@@ -23,30 +24,112 @@ pub struct StakeStorage {
     pub stakes: Mapping<AccountId, Balance>,
     // unstakes
     pub total_unstake: Balance,
+    // unstakes initialized before the queue was introduced. Kept under the same key so that they are readable after
+    // `set_code_hash` and moved to `unstakes_queue` the first time the unstakes of `account` are modified.
     pub unstakes: Mapping<AccountId, Vec<Unstake>>,
+    // queue of `account` unstakes is stored under keys `(account, unstakes_id.0..unstakes_id.1)` (wrapping).
+    pub unstakes_queue: Mapping<(AccountId, UnstakeId), Unstake>,
+    pub unstakes_id: Mapping<AccountId, (UnstakeId, UnstakeId)>,
 }
 
 impl Default for StakeStorage {
     fn default() -> Self {
         Self {
             want: [0; 32].into(),
+            minimal_unstake_period: Default::default(),
             unstake_period: Default::default(),
             maximal_number_of_unstakes: Default::default(),
             total_stake: Default::default(),
             total_unstake: Default::default(),
             stakes: Default::default(),
             unstakes: Default::default(),
+            unstakes_queue: Default::default(),
+            unstakes_id: Default::default(),
         }
     }
 }
 
 impl StakeStorage {
+    // sets the parameters checking the same bounds as `StakeManage` does when they are changed.
+    pub fn init_parameters(
+        &mut self,
+        minimal_unstake_period: &Timestamp,
+        unstake_period: &Timestamp,
+        maximal_number_of_unstakes: &u64,
+    ) -> Result<(), StakeError> {
+        if *unstake_period < *minimal_unstake_period
+            || *maximal_number_of_unstakes == 0
+            || *maximal_number_of_unstakes > MAXIMAL_NUMBER_OF_UNSTAKES_LIMIT
+        {
+            return Err(StakeError::WrongParameters)
+        }
+        self.minimal_unstake_period = *minimal_unstake_period;
+        self.change_unstake_period(unstake_period);
+        self.change_maximal_number_of_unstakes(maximal_number_of_unstakes);
+        Ok(())
+    }
+
     pub fn stake_of(&self, account: &AccountId) -> Balance {
         self.stakes.get(account).unwrap_or_default()
     }
 
+    // returns the (head, tail) of `account` unstakes queue. Head is the id of the oldest unstake and tail is the id of the next one.
+    pub fn unstakes_id_of(&self, account: &AccountId) -> (UnstakeId, UnstakeId) {
+        self.unstakes_id.get(account).unwrap_or_default()
+    }
+
+    pub fn number_of_unstakes_of(&self, account: &AccountId) -> u32 {
+        if let Some(legacy_unstakes) = self.unstakes.get(account) {
+            return legacy_unstakes.len() as u32
+        }
+        let (head, tail) = self.unstakes_id_of(account);
+        tail.wrapping_sub(head)
+    }
+
+    // views skip unstakes missing from the queue instead of failing, modifications return `UnstakeNotInitialized`.
     pub fn initialized_unstakes_of(&self, account: &AccountId) -> Vec<Unstake> {
-        self.unstakes.get(account).unwrap_or_default()
+        if let Some(legacy_unstakes) = self.unstakes.get(account) {
+            return legacy_unstakes
+        }
+        let (head, tail) = self.unstakes_id_of(account);
+        let mut unstakes = Vec::new();
+        let mut id = head;
+        while id != tail {
+            if let Ok(unstake) = self.unstake_in_queue(account, id) {
+                unstakes.push(unstake);
+            }
+            id = id.wrapping_add(1);
+        }
+        unstakes
+    }
+
+    // returns the unstake of `account` stored under `id`. Every id between head and tail of the queue holds one.
+    fn unstake_in_queue(&self, account: &AccountId, id: UnstakeId) -> Result<Unstake, StakeError> {
+        self.unstakes_queue
+            .get(&(*account, id))
+            .ok_or(StakeError::UnstakeNotInitialized)
+    }
+
+    // moves the legacy unstakes of `account` to the (then empty) queue.
+    // the queue is modified only after this migration so both can't hold unstakes of `account` at the same time.
+    fn migrate_legacy_unstakes_of(&mut self, account: &AccountId) {
+        if let Some(legacy_unstakes) = self.unstakes.get(account) {
+            self.unstakes.remove(account);
+            let mut tail: UnstakeId = 0;
+            for unstake in legacy_unstakes.iter() {
+                self.unstakes_queue.insert(&(*account, tail), unstake);
+                tail = tail.wrapping_add(1);
+            }
+            self.set_unstakes_id_of(account, 0, tail);
+        }
+    }
+
+    fn set_unstakes_id_of(&mut self, account: &AccountId, head: UnstakeId, tail: UnstakeId) {
+        if head == tail {
+            self.unstakes_id.remove(account);
+        } else {
+            self.unstakes_id.insert(account, &(head, tail));
+        }
     }
     pub fn change_unstake_period(&mut self, unstake_period: &Timestamp) {
         if self.unstake_period != *unstake_period {
@@ -106,44 +189,53 @@ impl StakeStorage {
         Ok(new_stake == 0)
     }
 
-    // decrease up to amount from user unstakes
+    // decrease up to amount from user unstakes starting from the latest one.
     // rerutns amount slashed.
-    pub fn decrease_unstakes_of(&mut self, account: &AccountId, amount: &Balance) -> Balance {
+    pub fn decrease_unstakes_of(&mut self, account: &AccountId, amount: &Balance) -> Result<Balance, StakeError> {
+        self.migrate_legacy_unstakes_of(account);
         let mut to_slash = *amount;
-        let mut unstakes = self.initialized_unstakes_of(account);
-        while !unstakes.is_empty() {
-            let mut unstake = unstakes.pop().unwrap();
+        let (head, mut tail) = self.unstakes_id_of(account);
+        while tail != head && to_slash > 0 {
+            let id = tail.wrapping_sub(1);
+            let mut unstake = self.unstake_in_queue(account, id)?;
 
-            if to_slash <= unstake.amount {
+            if to_slash < unstake.amount {
                 unstake.amount -= to_slash;
-                if unstake.amount != 0 {
-                    unstakes.push(unstake);
-                }
+                self.unstakes_queue.insert(&(*account, id), &unstake);
                 to_slash = 0;
-                break
             } else {
                 to_slash -= unstake.amount;
+                self.unstakes_queue.remove(&(*account, id));
+                tail = id;
             }
         }
+        self.set_unstakes_id_of(account, head, tail);
 
-        if unstakes.len() > 0 {
-            self.unstakes.insert(account, &unstakes);
-        } else {
-            self.unstakes.remove(account);
-        }
-
-        amount - to_slash
+        Ok(amount - to_slash)
     }
 
     pub fn stake_and_unstakes_initialized_after(&self, account: &AccountId, timestamp: &Timestamp) -> Balance {
         let mut stake_and_unstakes = self.stake_of(&account);
-        let unstakes = self.initialized_unstakes_of(account);
-
-        for unstake in unstakes.iter().rev() {
-            if unstake.init_time >= *timestamp {
-                stake_and_unstakes += unstake.amount;
-            } else {
-                break
+        if let Some(legacy_unstakes) = self.unstakes.get(account) {
+            for unstake in legacy_unstakes.iter().rev() {
+                if unstake.init_time >= *timestamp {
+                    stake_and_unstakes += unstake.amount;
+                } else {
+                    break
+                }
+            }
+            return stake_and_unstakes
+        }
+        let (head, mut id) = self.unstakes_id_of(account);
+
+        while id != head {
+            id = id.wrapping_sub(1);
+            if let Ok(unstake) = self.unstake_in_queue(account, id) {
+                if unstake.init_time >= *timestamp {
+                    stake_and_unstakes += unstake.amount;
+                } else {
+                    break
+                }
             }
         }
         stake_and_unstakes
@@ -155,48 +247,118 @@ impl StakeStorage {
         amount: &Balance,
         timestamp: &Timestamp,
     ) -> Result<(), StakeError> {
-        let mut unstakes = self.initialized_unstakes_of(account);
-        if unstakes.len() as u64 >= self.maximal_number_of_unstakes {
+        self.migrate_legacy_unstakes_of(account);
+        let (head, tail) = self.unstakes_id_of(account);
+        if tail.wrapping_sub(head) as u64 >= self.maximal_number_of_unstakes {
             return Err(StakeError::ToManyUnstakes)
         }
-        unstakes.push(Unstake {
-            init_time: *timestamp,
-            amount: *amount,
-        });
-
-        self.unstakes.insert(account, &unstakes);
+        self.unstakes_queue.insert(
+            &(*account, tail),
+            &Unstake {
+                init_time: *timestamp,
+                amount: *amount,
+            },
+        );
+        self.set_unstakes_id_of(account, head, tail.wrapping_add(1));
 
         self.increase_total_unstake(amount)?;
         Ok(())
     }
 
+    // removes unstakes of `account` that are ready at `timestamp` up to summed `max_amount`, splitting the last one if needed.
+    // returns their summed amount, the removed unstakes (or their parts) and the number of remaining ones.
     pub fn deregister_ready_unstakes(
         &mut self,
         account: &AccountId,
         timestamp: &Timestamp,
-    ) -> Result<Balance, StakeError> {
-        let unstakes = self.initialized_unstakes_of(account);
-        if unstakes.len() == 0 {
+        max_amount: &Balance,
+    ) -> Result<(Balance, Vec<Unstake>, u32), StakeError> {
+        self.migrate_legacy_unstakes_of(account);
+        let (mut head, tail) = self.unstakes_id_of(account);
+        if head == tail {
             return Err(StakeError::NoInitializedUnstakes)
         }
 
         let mut amount: Balance = 0;
+        let mut unstakes = Vec::new();
         let unstake_period = self.unstake_period;
-        if *timestamp < unstakes[0].init_time + unstake_period {
+        while head != tail && amount < *max_amount {
+            let mut unstake = self.unstake_in_queue(account, head)?;
+            if *timestamp < unstake.init_time + unstake_period {
+                break
+            }
+            let to_release = *max_amount - amount;
+            if to_release < unstake.amount {
+                unstake.amount -= to_release;
+                self.unstakes_queue.insert(&(*account, head), &unstake);
+                amount += to_release;
+                unstakes.push(Unstake {
+                    init_time: unstake.init_time,
+                    amount: to_release,
+                });
+                break
+            }
+            amount += unstake.amount;
+            unstakes.push(unstake);
+            self.unstakes_queue.remove(&(*account, head));
+            head = head.wrapping_add(1);
+        }
+        if unstakes.len() == 0 {
             return Err(StakeError::TooEarly)
         }
+        self.set_unstakes_id_of(account, head, tail);
+
+        self.decrease_total_unstake(&amount)?;
 
-        let mut index: usize = 0;
-        while index < unstakes.len() && *timestamp >= unstakes[index].init_time + unstake_period {
-            amount += unstakes[index].amount;
-            index += 1;
+        Ok((amount, unstakes, tail.wrapping_sub(head)))
+    }
+
+    // removes the unstake at `index` of `account` unstakes and returns its amount.
+    // later unstakes are moved one position back to keep the queue contiguous, so it costs O(n) storage writes
+    // where n is the number of unstakes after `index`. n < `maximal_number_of_unstakes` <= `MAXIMAL_NUMBER_OF_UNSTAKES_LIMIT`.
+    pub fn deregister_unstake(&mut self, account: &AccountId, index: &u32) -> Result<Balance, StakeError> {
+        self.migrate_legacy_unstakes_of(account);
+        let (head, tail) = self.unstakes_id_of(account);
+        if head == tail {
+            return Err(StakeError::NoInitializedUnstakes)
+        }
+        if *index >= tail.wrapping_sub(head) {
+            return Err(StakeError::UnstakeNotInitialized)
         }
 
-        if index < unstakes.len() {
-            self.unstakes.insert(account, &unstakes[index..].to_vec());
-        } else {
-            self.unstakes.remove(account);
+        let mut id = head.wrapping_add(*index);
+        let amount = self.unstake_in_queue(account, id)?.amount;
+        let last_id = tail.wrapping_sub(1);
+        while id != last_id {
+            let next_id = id.wrapping_add(1);
+            let next_unstake = self.unstake_in_queue(account, next_id)?;
+            self.unstakes_queue.insert(&(*account, id), &next_unstake);
+            id = next_id;
+        }
+        self.unstakes_queue.remove(&(*account, last_id));
+        self.set_unstakes_id_of(account, head, last_id);
+
+        self.decrease_total_unstake(&amount)?;
+
+        Ok(amount)
+    }
+
+    // removes all unstakes of `account` and returns their summed amount.
+    pub fn deregister_all_unstakes(&mut self, account: &AccountId) -> Result<Balance, StakeError> {
+        self.migrate_legacy_unstakes_of(account);
+        let (mut head, tail) = self.unstakes_id_of(account);
+        if head == tail {
+            return Err(StakeError::NoInitializedUnstakes)
+        }
+
+        let mut amount: Balance = 0;
+        while head != tail {
+            let unstake = self.unstake_in_queue(account, head)?;
+            amount = amount.checked_add(unstake.amount).ok_or(MathError::Add)?;
+            self.unstakes_queue.remove(&(*account, head));
+            head = head.wrapping_add(1);
         }
+        self.set_unstakes_id_of(account, head, tail);
 
         self.decrease_total_unstake(&amount)?;
 
@@ -233,6 +395,105 @@ impl StakeTimesStorage {
     }
 }
 
+#[derive(Debug, Default)]
+#[openbrush::storage_item]
+pub struct StakeCheckpointsStorage {
+    pub checkpoints: Mapping<(AccountId, u32), Checkpoint>,
+    pub number_of_checkpoints: Mapping<AccountId, u32>,
+}
+
+impl StakeCheckpointsStorage {
+    pub fn number_of_checkpoints_of(&self, account: &AccountId) -> u32 {
+        self.number_of_checkpoints.get(account).unwrap_or_default()
+    }
+
+    pub fn checkpoint_of(&self, account: &AccountId, index: &u32) -> Option<Checkpoint> {
+        self.checkpoints.get(&(*account, *index))
+    }
+
+    // stores `stake` of `account` valid from `timestamp`.
+    // if the last checkpoint was made at the same `timestamp` it is overwritten.
+    pub fn write_checkpoint(&mut self, account: &AccountId, timestamp: &Timestamp, stake: &Balance) {
+        let number_of_checkpoints = self.number_of_checkpoints_of(account);
+        let new_checkpoint = Checkpoint {
+            timestamp: *timestamp,
+            stake: *stake,
+        };
+        if number_of_checkpoints > 0 {
+            let last_index = number_of_checkpoints - 1;
+            if let Some(last_checkpoint) = self.checkpoint_of(account, &last_index) {
+                if last_checkpoint.timestamp == *timestamp {
+                    self.checkpoints.insert(&(*account, last_index), &new_checkpoint);
+                    return
+                }
+            }
+        }
+        self.checkpoints
+            .insert(&(*account, number_of_checkpoints), &new_checkpoint);
+        self.number_of_checkpoints.insert(account, &(number_of_checkpoints + 1));
+    }
+
+    // writes `stake` of `account` that predates checkpointing as valid from timestamp 0.
+    // does nothing if `account` already has a checkpoint or `stake` is 0.
+    pub fn backfill_checkpoint(&mut self, account: &AccountId, stake: &Balance) {
+        if *stake == 0 || self.number_of_checkpoints_of(account) > 0 {
+            return
+        }
+        self.write_checkpoint(account, &0, stake);
+    }
+
+    pub fn latest_stake_of(&self, account: &AccountId) -> Balance {
+        let number_of_checkpoints = self.number_of_checkpoints_of(account);
+        if number_of_checkpoints == 0 {
+            return 0
+        }
+        self.checkpoint_of(account, &(number_of_checkpoints - 1))
+            .map(|checkpoint| checkpoint.stake)
+            .unwrap_or_default()
+    }
+
+    // returns the stake of `account` from the last checkpoint made not later than `timestamp`.
+    pub fn stake_at(&self, account: &AccountId, timestamp: &Timestamp) -> Balance {
+        let index = checkpoint_index_at(self.number_of_checkpoints_of(account), timestamp, |index| {
+            self.checkpoint_of(account, &index)
+                .map(|checkpoint| checkpoint.timestamp)
+        });
+        match index {
+            Some(index) => {
+                self.checkpoint_of(account, &index)
+                    .map(|checkpoint| checkpoint.stake)
+                    .unwrap_or_default()
+            }
+            None => 0,
+        }
+    }
+}
+
+// returns the index of the last checkpoint made not later than `timestamp`. None if there is no such checkpoint.
+// `timestamp_of` returns timestamp of the checkpoint at given index. Checkpoints must be sorted by timestamp.
+pub fn checkpoint_index_at<F: Fn(u32) -> Option<Timestamp>>(
+    number_of_checkpoints: u32,
+    timestamp: &Timestamp,
+    timestamp_of: F,
+) -> Option<u32> {
+    // binary search for the first checkpoint made after `timestamp`
+    let mut low: u32 = 0;
+    let mut high: u32 = number_of_checkpoints;
+    while low < high {
+        let mid = low + (high - low) / 2;
+        match timestamp_of(mid) {
+            Some(checkpoint_timestamp) if checkpoint_timestamp > *timestamp => high = mid,
+            _ => low = mid + 1,
+        }
+    }
+
+    if low == 0 {
+        None
+    } else {
+        Some(low - 1)
+    }
+}
+
 pub const STORAGE_KEY2: u32 = openbrush::storage_unique_key!(StakeCounterStorage);
 #[derive(Debug, Default)]
 #[openbrush::storage_item]
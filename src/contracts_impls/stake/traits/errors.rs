@@ -1,5 +1,6 @@
 use ink::LangError;
 use openbrush::contracts::{
+    access_control::AccessControlError,
     ownable::OwnableError,
     psp22::PSP22Error,
 };
@@ -23,7 +24,9 @@ pub enum StakeError {
     Unstaking,
     ToManyUnstakes,
     StakeIsZero,
+    WrongParameters,
     OwnableError(OwnableError),
+    AccessControlError(AccessControlError),
 }
 
 impl From<LangError> for StakeError {
@@ -49,6 +52,12 @@ impl From<OwnableError> for StakeError {
     }
 }
 
+impl From<AccessControlError> for StakeError {
+    fn from(error: AccessControlError) -> Self {
+        StakeError::AccessControlError(error)
+    }
+}
+
 #[derive(Encode, Decode, Debug)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub enum MathError {
@@ -1,3 +1,5 @@
+use super::Unstake;
+use ink::prelude::vec::Vec;
 use openbrush::traits::{
     AccountId,
     Balance,
@@ -7,7 +9,15 @@ use openbrush::traits::{
 pub trait EmitStakeEvents {
     fn _emit_staked_event(&self, caller: &AccountId, amount: &Balance);
     fn _emit_initialized_unstake_event(&self, caller: &AccountId, amount: &Balance);
-    fn _emit_unstake_event(&self, caller: &AccountId);
+    fn _emit_unstake_event(
+        &self,
+        caller: &AccountId,
+        recipient: &AccountId,
+        amount: &Balance,
+        unstakes: &Vec<Unstake>,
+        remaining_unstakes: &u32,
+    );
+    fn _emit_unstake_cancelled_event(&self, caller: &AccountId, amount: &Balance);
     fn _emit_rewarded_event(&self, account: &AccountId, amount: &Balance);
     fn _emit_slashed_event(&self, account: &AccountId, amount: &Balance);
     fn _emit_unstake_period_changed_event(&self, unstake_period: &Timestamp);
@@ -11,8 +11,16 @@ pub use openbrush::traits::{
 };
 pub use structs::*;
 
+use openbrush::contracts::access_control::RoleType;
+
 pub type UnstakeId = u32;
 
+/// Role allowed to call `StakeManage` messages.
+pub const STAKE_ADMIN: RoleType = ink::selector_id!("STAKE_ADMIN");
+
+/// Upper bound of `maximal_number_of_unstakes`. Keeps the cost of `cancel_unstake`, linear in the number of unstakes, bounded.
+pub const MAXIMAL_NUMBER_OF_UNSTAKES_LIMIT: u64 = 256;
+
 pub use ink::prelude::{
     vec,
     vec::*,
@@ -28,6 +36,10 @@ pub trait StakeView {
     #[ink(message)]
     fn unstake_period(&self) -> Timestamp;
 
+    /// Returns the lower bound for the `unstake_period` set at construction.
+    #[ink(message)]
+    fn minimal_unstake_period(&self) -> Timestamp;
+
     /// Returns the maximal number of initialized unstakes an account can have at one time
     #[ink(message)]
     fn maximal_number_of_unstakes(&self) -> u64;
@@ -84,6 +96,40 @@ pub trait Stake {
     /// Retuns wrapeed error from `want` if transfer fails (can be wrapped `PSP22Error`)
     #[ink(message)]
     fn unstake(&mut self) -> Result<Balance, StakeError>;
+
+    /// Transfers up to `max_amount` of `want` asset from `caller` initialized unstakes that are ready for unstaking to `recipient`.
+    /// If only part of an unstake is needed to reach `max_amount` the rest of it stays initialized.
+    ///
+    /// On success emits `Unstaked` event.
+    ///
+    /// # Errors
+    /// Returns `AmountIsZero` if `max_amount` is 0.
+    /// Returns `NoInitializedUnstakes` if `caller` has no initialized unstakes.
+    /// Returns `TooEarly` if no of `caller` initialized unstakes is ready for unstaking.
+    /// Retuns wrapeed error from `want` if transfer fails (can be wrapped `PSP22Error`)
+    #[ink(message)]
+    fn unstake_to(&mut self, recipient: AccountId, max_amount: Balance) -> Result<Balance, StakeError>;
+
+    /// Cancels the `caller` initialized unstake at `index` of `initialized_unstakes_of` and moves its amount back to stake.
+    /// Later unstakes are shifted back by one, so the cost grows linearly with their number (bounded by `maximal_number_of_unstakes`,
+    /// which can not exceed `MAXIMAL_NUMBER_OF_UNSTAKES_LIMIT`).
+    ///
+    /// On success emits `UnstakeCancelled` event.
+    ///
+    /// # Errors
+    /// Returns `NoInitializedUnstakes` if `caller` has no initialized unstakes.
+    /// Returns `UnstakeNotInitialized` if there is no `caller` initialized unstake at `index`.
+    #[ink(message)]
+    fn cancel_unstake(&mut self, index: u32) -> Result<Balance, StakeError>;
+
+    /// Cancels all of the `caller` initialized unstakes and moves their amount back to stake.
+    ///
+    /// On success emits `UnstakeCancelled` event.
+    ///
+    /// # Errors
+    /// Returns `NoInitializedUnstakes` if `caller` has no initialized unstakes.
+    #[ink(message)]
+    fn restake_all_unstakes(&mut self) -> Result<Balance, StakeError>;
 }
 #[ink::trait_definition]
 pub trait StakeManage {
@@ -93,7 +139,8 @@ pub trait StakeManage {
     ///
     /// # Errors
     ///
-    /// Returns `OwnableError` if onwer required and the `caller` is not the owner.
+    /// Returns `AccessControlError` if the `caller` doesn't have the `STAKE_ADMIN` role.
+    /// Returns `WrongParameters` if `unstake_period` is lower than `minimal_unstake_period`.
     #[ink(message)]
     fn change_unstake_period(&mut self, unstake_period: Timestamp) -> Result<(), StakeError>;
 
@@ -103,7 +150,8 @@ pub trait StakeManage {
     ///
     /// # Errors
     ///
-    /// Returns `OwnableError` if onwer required and the `caller` is not the owner.
+    /// Returns `AccessControlError` if the `caller` doesn't have the `STAKE_ADMIN` role.
+    /// Returns `WrongParameters` if `maximal_number_of_unstakes` is 0 or greater than `MAXIMAL_NUMBER_OF_UNSTAKES_LIMIT`.
     #[ink(message)]
     fn change_maximal_number_of_unstakes(&mut self, maximal_number_of_unstakes: u64) -> Result<(), StakeError>;
 }
@@ -119,6 +167,15 @@ pub trait StakeTimes {
     fn last_stake_timestamp_of(&self, account: AccountId) -> Option<Timestamp>;
 }
 
+#[ink::trait_definition]
+pub trait StakeCheckpoints {
+    /// Returns the stake of `account` at `timestamp` based on the checkpoint history.
+    /// Stake added after `timestamp` is not included.
+    /// Stake made before checkpointing was introduced is treated as held since timestamp 0.
+    #[ink(message)]
+    fn stake_at(&self, account: AccountId, timestamp: Timestamp) -> Balance;
+}
+
 #[ink::trait_definition]
 pub trait StakeCounter {
     /// Returns sum of all stakes ever done. May overflow.
@@ -168,6 +225,23 @@ pub trait StakeTransfer {
     fn _on_slash(&self, amount: &Balance) -> Result<(), StakeError>;
 }
 
+pub trait StakeCheckpointsInternal {
+    /// Writes the checkpoint of the current `account` stake. Must be called whenever `account` stake changes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MathError` if an implementation that tracks values derived from the stake overflows.
+    fn _checkpoint_stake_of(&mut self, account: &AccountId) -> Result<(), StakeError>;
+
+    /// Writes the checkpoint of the current `account` stake valid from timestamp 0 if `account` has stake but no checkpoints,
+    /// which is the case for stakes made before checkpointing was introduced. Must be called before `account` stake changes.
+    fn _backfill_stake_checkpoint_of(&mut self, account: &AccountId);
+
+    /// Returns the stake of `account` at `timestamp` based on the checkpoint history.
+    /// If `account` has no checkpoints its stake didn't change since checkpointing was introduced, so the current stake is returned.
+    fn _stake_at(&self, account: &AccountId, timestamp: &Timestamp) -> Balance;
+}
+
 pub trait StakeInternal {
     /// Rewards `account` by increasing its stake by `amount`. Calls `on_reward` method.
     ///
@@ -180,4 +254,24 @@ pub trait StakeInternal {
     /// # Errors
     /// Returns `AmountIsZero` if `amount is 0.
     fn _slash(&mut self, account: &AccountId, amount: &Balance) -> Result<Balance, StakeError>;
+
+    /// Deregisters up to `max_amount` of `caller` ready unstakes and transfers it to `recipient`.
+    ///
+    /// On success emits `Unstaked` event.
+    ///
+    /// # Errors
+    /// Returns `NoInitializedUnstakes` or `TooEarly` if `caller` has no unstakes ready for unstaking.
+    /// Returns `PSP22Error` if the transfer to `recipient` failed.
+    fn _unstake(
+        &mut self,
+        caller: &AccountId,
+        recipient: &AccountId,
+        max_amount: &Balance,
+    ) -> Result<Balance, StakeError>;
+
+    /// Moves `amount` that was deregistered from `account` unstakes back to its stake.
+    ///
+    /// # Errors
+    /// Returns `MathError` if the stake of `account` or the total stake overflows.
+    fn _restake(&mut self, account: &AccountId, amount: &Balance) -> Result<(), StakeError>;
 }
@@ -17,3 +17,14 @@ pub struct Unstake {
     /// amount initialized for unstaking.
     pub amount: Balance,
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+
+/// Represents the `stake` of an account that was valid from `timestamp` until the next checkpoint.
+pub struct Checkpoint {
+    /// timestamp from which the checkpoint is valid.
+    pub timestamp: Timestamp,
+    /// stake of an account at `timestamp`.
+    pub stake: Balance,
+}
@@ -0,0 +1,216 @@
+pub mod storage;
+pub use storage::*;
+
+use crate::contracts_impls::{
+    stake::traits::MathError,
+    timestamp_mock::impls::{
+        TimestampMockInternal,
+        TimestampMockStorage,
+    },
+    treasury::traits::*,
+    vesting::impls::linear_vested_at,
+};
+
+use ink::env::DefaultEnvironment;
+
+use openbrush::{
+    contracts::{
+        ownable::{
+            self,
+            only_owner,
+        },
+        psp22::*,
+    },
+    modifiers,
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+        Timestamp,
+    },
+};
+
+pub trait TreasuryViewImpl: Storage<TreasuryStorage> + TreasuryInternal + TimestampMockInternal {
+    fn available_balance(&self, asset: Asset) -> Balance {
+        self._balance_of(&asset)
+            .saturating_sub(self.data::<TreasuryStorage>().reserved_of(&asset))
+    }
+
+    fn reserved_balance(&self, asset: Asset) -> Balance {
+        self.data::<TreasuryStorage>().reserved_of(&asset)
+    }
+
+    fn stream(&self, stream_id: StreamId) -> Option<Stream> {
+        self.data::<TreasuryStorage>().stream_of(&stream_id)
+    }
+
+    fn withdrawable_from_stream(&self, stream_id: StreamId) -> Balance {
+        match self.data::<TreasuryStorage>().stream_of(&stream_id) {
+            Some(stream) => {
+                self._vested_at(&stream, &self._timestamp())
+                    .saturating_sub(stream.withdrawn)
+            }
+            None => 0,
+        }
+    }
+
+    fn number_of_streams(&self) -> StreamId {
+        self.data::<TreasuryStorage>().next_stream_id
+    }
+}
+
+pub trait TreasuryImpl:
+    Storage<TreasuryStorage>
+    + Storage<ownable::Data>
+    + TreasuryInternal
+    + Storage<TimestampMockStorage>
+    + TimestampMockInternal
+    + TreasuryViewImpl
+    + EmitTreasuryEvents
+{
+    #[modifiers(only_owner())]
+    fn transfer_native(&mut self, to: AccountId, amount: Balance) -> Result<(), TreasuryError> {
+        if amount == 0 {
+            return Err(TreasuryError::AmountIsZero)
+        }
+        if self.available_balance(Asset::Native) < amount {
+            return Err(TreasuryError::InsufficientFunds)
+        }
+        self._transfer(&Asset::Native, &to, &amount)
+    }
+
+    #[modifiers(only_owner())]
+    fn transfer_psp22(&mut self, token: AccountId, to: AccountId, amount: Balance) -> Result<(), TreasuryError> {
+        if amount == 0 {
+            return Err(TreasuryError::AmountIsZero)
+        }
+        if self.available_balance(Asset::PSP22(token)) < amount {
+            return Err(TreasuryError::InsufficientFunds)
+        }
+        self._transfer(&Asset::PSP22(token), &to, &amount)
+    }
+
+    #[modifiers(only_owner())]
+    fn approve_psp22(&mut self, token: AccountId, spender: AccountId, amount: Balance) -> Result<(), TreasuryError> {
+        // the spender can't be allowed to take the `token` reserved for streams
+        if self.available_balance(Asset::PSP22(token)) < amount {
+            return Err(TreasuryError::InsufficientFunds)
+        }
+        PSP22Ref::approve(&token, spender, amount)?;
+        self._emit_approved_event(&token, &spender, &amount);
+        Ok(())
+    }
+
+    /// # Storage modifications
+    /// [TreasuryStorage]
+    /// `streams` of key `next_stream_id` set to the new stream.
+    /// `next_stream_id` increased by 1.
+    /// `reserved` of key `asset` increased by `amount`.
+    #[modifiers(only_owner())]
+    fn create_stream(
+        &mut self,
+        asset: Asset,
+        recipient: AccountId,
+        amount: Balance,
+        start: Timestamp,
+        duration: Timestamp,
+    ) -> Result<StreamId, TreasuryError> {
+        if amount == 0 {
+            return Err(TreasuryError::AmountIsZero)
+        }
+        if duration == 0 {
+            return Err(TreasuryError::WrongParameters)
+        }
+        if self.available_balance(asset) < amount {
+            return Err(TreasuryError::InsufficientFunds)
+        }
+        let stream = Stream {
+            asset,
+            recipient,
+            amount,
+            withdrawn: 0,
+            start,
+            duration,
+        };
+        self.data::<TreasuryStorage>().reserve(&asset, &amount)?;
+        let stream_id = self.data::<TreasuryStorage>().add_stream(&stream)?;
+        self._emit_stream_created_event(&stream_id, &stream);
+        Ok(stream_id)
+    }
+
+    /// # Storage modifications
+    /// [TreasuryStorage]
+    /// `streams` of key `stream_id` field withdrawn increased by the withdrawn amount.
+    /// `reserved` of key stream asset decreased by the withdrawn amount.
+    fn withdraw_from_stream(&mut self, stream_id: StreamId) -> Result<Balance, TreasuryError> {
+        let mut stream = self
+            .data::<TreasuryStorage>()
+            .stream_of(&stream_id)
+            .ok_or(TreasuryError::NoSuchStream)?;
+        if Self::env().caller() != stream.recipient {
+            return Err(TreasuryError::CallerIsNotRecipient)
+        }
+        let amount = self
+            ._vested_at(&stream, &self._timestamp())
+            .saturating_sub(stream.withdrawn);
+        if amount == 0 {
+            return Err(TreasuryError::NothingToWithdraw)
+        }
+        stream.withdrawn = stream.withdrawn.checked_add(amount).ok_or(MathError::Add)?;
+        self.data::<TreasuryStorage>().streams.insert(&stream_id, &stream);
+        self.data::<TreasuryStorage>().release(&stream.asset, &amount)?;
+        self._transfer(&stream.asset, &stream.recipient, &amount)?;
+        self._emit_stream_withdrawn_event(&stream_id, &amount);
+        Ok(amount)
+    }
+
+    /// # Storage modifications
+    /// [TreasuryStorage]
+    /// `streams` of key `stream_id` removed.
+    /// `reserved` of key stream asset decreased by the not withdrawn amount.
+    #[modifiers(only_owner())]
+    fn cancel_stream(&mut self, stream_id: StreamId) -> Result<(), TreasuryError> {
+        let stream = self
+            .data::<TreasuryStorage>()
+            .stream_of(&stream_id)
+            .ok_or(TreasuryError::NoSuchStream)?;
+        let vested_not_withdrawn = self
+            ._vested_at(&stream, &self._timestamp())
+            .saturating_sub(stream.withdrawn);
+        self.data::<TreasuryStorage>().streams.remove(&stream_id);
+        self.data::<TreasuryStorage>()
+            .release(&stream.asset, &(stream.amount - stream.withdrawn))?;
+        if vested_not_withdrawn > 0 {
+            self._transfer(&stream.asset, &stream.recipient, &vested_not_withdrawn)?;
+        }
+        self._emit_stream_cancelled_event(&stream_id);
+        Ok(())
+    }
+}
+
+impl<T: Storage<TreasuryStorage> + EmitTreasuryEvents> TreasuryInternal for T {
+    default fn _balance_of(&self, asset: &Asset) -> Balance {
+        match asset {
+            Asset::Native => Self::env().balance(),
+            Asset::PSP22(token) => PSP22Ref::balance_of(token, Self::env().account_id()),
+        }
+    }
+
+    default fn _transfer(&mut self, asset: &Asset, to: &AccountId, amount: &Balance) -> Result<(), TreasuryError> {
+        match asset {
+            Asset::Native => {
+                match ink::env::transfer::<DefaultEnvironment>(*to, *amount) {
+                    Ok(()) => (),
+                    Err(_v) => return Err(TreasuryError::TransferError),
+                };
+            }
+            Asset::PSP22(token) => PSP22Ref::transfer(token, *to, *amount, ink::prelude::vec![])?,
+        }
+        self._emit_transferred_event(asset, to, amount);
+        Ok(())
+    }
+
+    default fn _vested_at(&self, stream: &Stream, timestamp: &Timestamp) -> Balance {
+        linear_vested_at(&stream.amount, &stream.start, &stream.duration, timestamp)
+    }
+}
@@ -0,0 +1,57 @@
+use openbrush::{
+    storage::Mapping,
+    traits::Balance,
+};
+
+use crate::contracts_impls::{
+    stake::traits::MathError,
+    treasury::traits::{
+        Asset,
+        Stream,
+        StreamId,
+        TreasuryError,
+    },
+};
+
+#[derive(Debug, Default)]
+#[openbrush::storage_item]
+pub struct TreasuryStorage {
+    // streams
+    pub next_stream_id: StreamId,
+    pub streams: Mapping<StreamId, Stream>,
+    // amounts reserved for streams
+    pub reserved: Mapping<Asset, Balance>,
+}
+
+impl TreasuryStorage {
+    pub fn reserved_of(&self, asset: &Asset) -> Balance {
+        self.reserved.get(asset).unwrap_or_default()
+    }
+
+    pub fn reserve(&mut self, asset: &Asset, amount: &Balance) -> Result<(), TreasuryError> {
+        let reserved = self.reserved_of(asset).checked_add(*amount).ok_or(MathError::Add)?;
+        self.reserved.insert(asset, &reserved);
+        Ok(())
+    }
+
+    pub fn release(&mut self, asset: &Asset, amount: &Balance) -> Result<(), TreasuryError> {
+        let reserved = self.reserved_of(asset).checked_sub(*amount).ok_or(MathError::Sub)?;
+        if reserved == 0 {
+            self.reserved.remove(asset);
+        } else {
+            self.reserved.insert(asset, &reserved);
+        }
+        Ok(())
+    }
+
+    pub fn stream_of(&self, stream_id: &StreamId) -> Option<Stream> {
+        self.streams.get(stream_id)
+    }
+
+    pub fn add_stream(&mut self, stream: &Stream) -> Result<StreamId, TreasuryError> {
+        let stream_id = self.next_stream_id;
+        self.streams.insert(&stream_id, stream);
+        self.next_stream_id = stream_id.checked_add(1).ok_or(MathError::Add)?;
+        Ok(stream_id)
+    }
+}
@@ -0,0 +1,3 @@
+pub mod impls;
+pub mod traits;
+pub mod transactions;
@@ -0,0 +1,43 @@
+use openbrush::contracts::{
+    ownable::OwnableError,
+    psp22::PSP22Error,
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+use crate::contracts_impls::stake::traits::MathError;
+
+#[derive(Encode, Decode, Debug)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum TreasuryError {
+    PSP22Error(PSP22Error),
+    MathError(MathError),
+    OwnableError(OwnableError),
+    TransferError,
+    AmountIsZero,
+    InsufficientFunds,
+    WrongParameters,
+    NoSuchStream,
+    CallerIsNotRecipient,
+    NothingToWithdraw,
+}
+
+impl From<PSP22Error> for TreasuryError {
+    fn from(error: PSP22Error) -> Self {
+        TreasuryError::PSP22Error(error)
+    }
+}
+
+impl From<MathError> for TreasuryError {
+    fn from(error: MathError) -> Self {
+        TreasuryError::MathError(error)
+    }
+}
+
+impl From<OwnableError> for TreasuryError {
+    fn from(error: OwnableError) -> Self {
+        TreasuryError::OwnableError(error)
+    }
+}
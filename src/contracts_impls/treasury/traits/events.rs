@@ -0,0 +1,19 @@
+use openbrush::traits::{
+    AccountId,
+    Balance,
+};
+
+use super::{
+    Asset,
+    Stream,
+    StreamId,
+};
+
+pub trait EmitTreasuryEvents {
+    fn _emit_transferred_event(&self, asset: &Asset, to: &AccountId, amount: &Balance);
+    fn _emit_approved_event(&self, token: &AccountId, spender: &AccountId, amount: &Balance);
+
+    fn _emit_stream_created_event(&self, stream_id: &StreamId, stream: &Stream);
+    fn _emit_stream_withdrawn_event(&self, stream_id: &StreamId, amount: &Balance);
+    fn _emit_stream_cancelled_event(&self, stream_id: &StreamId);
+}
@@ -0,0 +1,142 @@
+pub mod errors;
+pub mod events;
+pub mod structs;
+
+pub use errors::*;
+pub use events::*;
+pub use structs::*;
+
+use openbrush::traits::{
+    AccountId,
+    Balance,
+    Timestamp,
+};
+
+pub type StreamId = u32;
+
+#[ink::trait_definition]
+pub trait TreasuryView {
+    /// Returns the part of the treasury balance of `asset` that is not reserved for the streams.
+    #[ink(message)]
+    fn available_balance(&self, asset: Asset) -> Balance;
+
+    /// Returns the amount of `asset` reserved for the streams that are not fully withdrawn yet.
+    #[ink(message)]
+    fn reserved_balance(&self, asset: Asset) -> Balance;
+
+    /// Returns Some(stream) if the stream identified by `stream_id` exists and None otherwise.
+    #[ink(message)]
+    fn stream(&self, stream_id: StreamId) -> Option<Stream>;
+
+    /// Returns the amount that the recipient of `stream_id` can withdraw now.
+    #[ink(message)]
+    fn withdrawable_from_stream(&self, stream_id: StreamId) -> Balance;
+
+    /// Returns the number of created streams. `StreamId`s are assigned from 0.
+    #[ink(message)]
+    fn number_of_streams(&self) -> StreamId;
+}
+
+#[ink::trait_definition]
+pub trait Treasury {
+    /// Transfers `amount` of native token to `to`.
+    ///
+    /// On Success emits `Transferred` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AmountIsZero` if `amount` is 0.
+    /// Returns `InsufficientFunds` if the available balance of native token is lower than `amount`.
+    /// Returns `TransferError` if the transfer failed.
+    /// Returns wrapped `OwnableError` if the `caller` is not the `owner`.
+    #[ink(message)]
+    fn transfer_native(&mut self, to: AccountId, amount: Balance) -> Result<(), TreasuryError>;
+
+    /// Transfers `amount` of `token` to `to`.
+    ///
+    /// On Success emits `Transferred` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AmountIsZero` if `amount` is 0.
+    /// Returns `InsufficientFunds` if the available balance of `token` is lower than `amount`.
+    /// Returns wrapped `PSP22Error` if the transfer failed.
+    /// Returns wrapped `OwnableError` if the `caller` is not the `owner`.
+    #[ink(message)]
+    fn transfer_psp22(&mut self, token: AccountId, to: AccountId, amount: Balance) -> Result<(), TreasuryError>;
+
+    /// Sets the allowance of `spender` for the treasury `token` to `amount`.
+    ///
+    /// On Success emits `Approved` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InsufficientFunds` if `amount` is greater than the available (not reserved) balance of `token`.
+    /// Returns wrapped `PSP22Error` if the approval failed.
+    /// Returns wrapped `OwnableError` if the `caller` is not the `owner`.
+    #[ink(message)]
+    fn approve_psp22(&mut self, token: AccountId, spender: AccountId, amount: Balance) -> Result<(), TreasuryError>;
+
+    /// Creates the stream of `amount` of `asset` to `recipient` that vests linearly from `start` during `duration`.
+    /// `amount` is reserved and can not be spent by other treasury actions. Returns the id of created stream.
+    ///
+    /// On Success emits `StreamCreated` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AmountIsZero` if `amount` is 0.
+    /// Returns `WrongParameters` if `duration` is 0.
+    /// Returns `InsufficientFunds` if the available balance of `asset` is lower than `amount`.
+    /// Returns wrapped `OwnableError` if the `caller` is not the `owner`.
+    #[ink(message)]
+    fn create_stream(
+        &mut self,
+        asset: Asset,
+        recipient: AccountId,
+        amount: Balance,
+        start: Timestamp,
+        duration: Timestamp,
+    ) -> Result<StreamId, TreasuryError>;
+
+    /// Transfers the vested and not yet withdrawn part of `stream_id` to its recipient. Returns the withdrawn amount.
+    ///
+    /// On Success emits `StreamWithdrawn` and `Transferred` events.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NoSuchStream` if the stream doesn't exist.
+    /// Returns `CallerIsNotRecipient` if `caller` is not the recipient of the stream.
+    /// Returns `NothingToWithdraw` if nothing is vested since the last withdrawal.
+    /// Returns `TransferError` or wrapped `PSP22Error` if the transfer failed.
+    #[ink(message)]
+    fn withdraw_from_stream(&mut self, stream_id: StreamId) -> Result<Balance, TreasuryError>;
+
+    /// Cancels `stream_id`. The vested part is transferred to the recipient and the rest is released back to the treasury.
+    ///
+    /// On Success emits `StreamCancelled` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NoSuchStream` if the stream doesn't exist.
+    /// Returns `TransferError` or wrapped `PSP22Error` if the transfer failed.
+    /// Returns wrapped `OwnableError` if the `caller` is not the `owner`.
+    #[ink(message)]
+    fn cancel_stream(&mut self, stream_id: StreamId) -> Result<(), TreasuryError>;
+}
+
+pub trait TreasuryInternal {
+    /// Returns the balance of `asset` held by the treasury.
+    fn _balance_of(&self, asset: &Asset) -> Balance;
+
+    /// Transfers `amount` of `asset` to `to`.
+    ///
+    /// On Success emits `Transferred` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TransferError` or wrapped `PSP22Error` if the transfer failed.
+    fn _transfer(&mut self, asset: &Asset, to: &AccountId, amount: &Balance) -> Result<(), TreasuryError>;
+
+    /// Returns the amount of `stream` vested at `timestamp`.
+    fn _vested_at(&self, stream: &Stream, timestamp: &Timestamp) -> Balance;
+}
@@ -0,0 +1,37 @@
+use openbrush::traits::{
+    AccountId,
+    Balance,
+    Timestamp,
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+/// Asset that can be paid out by the treasury.
+pub enum Asset {
+    /// native token of the chain.
+    Native,
+    /// PSP22 token identified by its `AccountId`.
+    PSP22(AccountId),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+/// Represents `amount` of `asset` paid out linearly to `recipient` from `start` during `duration`.
+pub struct Stream {
+    /// streamed asset.
+    pub asset: Asset,
+    /// account that can withdraw from the stream.
+    pub recipient: AccountId,
+    /// total amount to be streamed.
+    pub amount: Balance,
+    /// amount already withdrawn by `recipient`.
+    pub withdrawn: Balance,
+    /// timestamp from which the stream starts to vest.
+    pub start: Timestamp,
+    /// time after `start` when the whole `amount` is vested.
+    pub duration: Timestamp,
+}
@@ -0,0 +1,76 @@
+//! Builders of the `Transaction`s that make the governor call the treasury.
+//! Each builder encodes the arguments of the corresponding `Treasury` message after its selector.
+
+use ink::prelude::vec::Vec;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+    Timestamp,
+};
+use scale::Encode;
+
+use crate::contracts_impls::{
+    govern::traits::Transaction,
+    treasury::traits::{
+        Asset,
+        StreamId,
+    },
+};
+
+pub const TRANSFER_NATIVE_SELECTOR: [u8; 4] = ink::selector_bytes!("Treasury::transfer_native");
+pub const TRANSFER_PSP22_SELECTOR: [u8; 4] = ink::selector_bytes!("Treasury::transfer_psp22");
+pub const APPROVE_PSP22_SELECTOR: [u8; 4] = ink::selector_bytes!("Treasury::approve_psp22");
+pub const CREATE_STREAM_SELECTOR: [u8; 4] = ink::selector_bytes!("Treasury::create_stream");
+pub const CANCEL_STREAM_SELECTOR: [u8; 4] = ink::selector_bytes!("Treasury::cancel_stream");
+
+fn treasury_transaction(treasury: &AccountId, selector: [u8; 4], input: Vec<u8>) -> Transaction {
+    Transaction {
+        callee: *treasury,
+        selector,
+        input,
+        transferred_value: 0,
+        gas_limit: None,
+        allow_failure: false,
+    }
+}
+
+pub fn transfer_native_transaction(treasury: &AccountId, to: &AccountId, amount: &Balance) -> Transaction {
+    treasury_transaction(treasury, TRANSFER_NATIVE_SELECTOR, (to, amount).encode())
+}
+
+pub fn transfer_psp22_transaction(
+    treasury: &AccountId,
+    token: &AccountId,
+    to: &AccountId,
+    amount: &Balance,
+) -> Transaction {
+    treasury_transaction(treasury, TRANSFER_PSP22_SELECTOR, (token, to, amount).encode())
+}
+
+pub fn approve_psp22_transaction(
+    treasury: &AccountId,
+    token: &AccountId,
+    spender: &AccountId,
+    amount: &Balance,
+) -> Transaction {
+    treasury_transaction(treasury, APPROVE_PSP22_SELECTOR, (token, spender, amount).encode())
+}
+
+pub fn create_stream_transaction(
+    treasury: &AccountId,
+    asset: &Asset,
+    recipient: &AccountId,
+    amount: &Balance,
+    start: &Timestamp,
+    duration: &Timestamp,
+) -> Transaction {
+    treasury_transaction(
+        treasury,
+        CREATE_STREAM_SELECTOR,
+        (asset, recipient, amount, start, duration).encode(),
+    )
+}
+
+pub fn cancel_stream_transaction(treasury: &AccountId, stream_id: &StreamId) -> Transaction {
+    treasury_transaction(treasury, CANCEL_STREAM_SELECTOR, stream_id.encode())
+}
@@ -0,0 +1,195 @@
+pub mod storage;
+pub use storage::*;
+
+use crate::contracts_impls::{
+    stake::{
+        impls::storage::data::StakeStorage,
+        traits::StakeTransfer,
+    },
+    timestamp_mock::impls::TimestampMockInternal,
+    vesting::traits::*,
+};
+
+use openbrush::{
+    contracts::{
+        ownable::{
+            self,
+            only_owner,
+        },
+        psp22::*,
+    },
+    modifiers,
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+        Timestamp,
+    },
+};
+
+impl<T: Storage<VestingStorage> + VestingInternal> VestingView for T {
+    fn vesting(&self, vesting_id: VestingId) -> Option<VestingSchedule> {
+        self.data::<VestingStorage>().schedule_of(&vesting_id)
+    }
+
+    fn releasable(&self, vesting_id: VestingId) -> Balance {
+        match self.data::<VestingStorage>().schedule_of(&vesting_id) {
+            Some(schedule) => self._releasable(&schedule),
+            None => 0,
+        }
+    }
+
+    fn number_of_vestings(&self) -> VestingId {
+        self.data::<VestingStorage>().next_vesting_id
+    }
+
+    fn total_vesting(&self) -> Balance {
+        self.data::<VestingStorage>().total_vesting
+    }
+}
+
+impl<
+        T: Storage<VestingStorage>
+            + Storage<ownable::Data>
+            + VestingInternal
+            + VestingFunding
+            + StakeTransfer
+            + EmitVestingEvents,
+    > Vesting for T
+{
+    /// # Storage modifications
+    /// [VestingStorage]
+    /// `schedules` of key `next_vesting_id` set to the new schedule.
+    /// `next_vesting_id` increased by 1.
+    /// `total_vesting` increased by `amount`.
+    #[modifiers(only_owner())]
+    fn create_vesting(
+        &mut self,
+        beneficiary: AccountId,
+        amount: Balance,
+        start: Timestamp,
+        duration: Timestamp,
+        kind: VestingKind,
+    ) -> Result<VestingId, VestingError> {
+        if amount == 0 {
+            return Err(VestingError::AmountIsZero)
+        }
+        if duration == 0 {
+            return Err(VestingError::WrongParameters)
+        }
+        self._fund_vesting(&amount)?;
+        let schedule = VestingSchedule {
+            beneficiary,
+            amount,
+            released: 0,
+            start,
+            duration,
+            kind,
+        };
+        let vesting_id = self.data::<VestingStorage>().add_schedule(&schedule)?;
+        self._emit_vesting_created_event(&vesting_id, &schedule);
+        Ok(vesting_id)
+    }
+
+    /// # Storage modifications
+    /// [VestingStorage]
+    /// `schedules` of key `vesting_id` field released increased by the released amount.
+    /// `total_vesting` decreased by the released amount.
+    fn release(&mut self, vesting_id: VestingId) -> Result<Balance, VestingError> {
+        let mut schedule = self
+            .data::<VestingStorage>()
+            .schedule_of(&vesting_id)
+            .ok_or(VestingError::NoSuchVesting)?;
+        if Self::env().caller() != schedule.beneficiary {
+            return Err(VestingError::CallerIsNotBeneficiary)
+        }
+        let amount = self._releasable(&schedule);
+        if amount == 0 {
+            return Err(VestingError::NothingToRelease)
+        }
+        schedule.released += amount;
+        self.data::<VestingStorage>().schedules.insert(&vesting_id, &schedule);
+        self.data::<VestingStorage>().decrease_total_vesting(&amount)?;
+        self._transfer_out(&schedule.beneficiary, &amount)?;
+        self._emit_vesting_released_event(&vesting_id, &amount);
+        Ok(amount)
+    }
+
+    /// # Storage modifications
+    /// [VestingStorage]
+    /// `schedules` of key `vesting_id` removed.
+    /// `total_vesting` decreased by the not released amount.
+    #[modifiers(only_owner())]
+    fn revoke(&mut self, vesting_id: VestingId) -> Result<(), VestingError> {
+        let schedule = self
+            .data::<VestingStorage>()
+            .schedule_of(&vesting_id)
+            .ok_or(VestingError::NoSuchVesting)?;
+        let releasable = self._releasable(&schedule);
+        let refunded = schedule.amount - schedule.released - releasable;
+
+        self.data::<VestingStorage>().schedules.remove(&vesting_id);
+        self.data::<VestingStorage>()
+            .decrease_total_vesting(&(schedule.amount - schedule.released))?;
+        if releasable > 0 {
+            self._transfer_out(&schedule.beneficiary, &releasable)?;
+        }
+        self._refund_vesting(&refunded)?;
+        self._emit_vesting_revoked_event(&vesting_id, &refunded);
+        Ok(())
+    }
+}
+
+/// Returns the part of `amount` vested linearly from `start` during `duration` at `timestamp`.
+pub fn linear_vested_at(amount: &Balance, start: &Timestamp, duration: &Timestamp, timestamp: &Timestamp) -> Balance {
+    if *timestamp <= *start {
+        return 0
+    }
+    let elapsed = *timestamp - *start;
+    if elapsed >= *duration {
+        return *amount
+    }
+    // elapsed < duration so the result is lower than amount
+    amount
+        .checked_mul(elapsed as u128)
+        .map(|vested| vested / *duration as u128)
+        .unwrap_or_else(|| *amount / *duration as u128 * elapsed as u128)
+}
+
+impl<T: Storage<VestingStorage> + TimestampMockInternal> VestingInternal for T {
+    default fn _vested_at(&self, schedule: &VestingSchedule, timestamp: &Timestamp) -> Balance {
+        match schedule.kind {
+            VestingKind::Cliff => {
+                if timestamp.saturating_sub(schedule.start) >= schedule.duration {
+                    schedule.amount
+                } else {
+                    0
+                }
+            }
+            VestingKind::Linear => linear_vested_at(&schedule.amount, &schedule.start, &schedule.duration, timestamp),
+        }
+    }
+
+    default fn _releasable(&self, schedule: &VestingSchedule) -> Balance {
+        self._vested_at(schedule, &self._timestamp())
+            .saturating_sub(schedule.released)
+    }
+}
+
+impl<T: Storage<VestingStorage> + Storage<StakeStorage>> VestingFunding for T {
+    /// Free `want` tokens are the contract balance that is neither staked, unstaking nor vesting.
+    default fn _fund_vesting(&mut self, amount: &Balance) -> Result<(), VestingError> {
+        let balance = PSP22Ref::balance_of(&self.data::<StakeStorage>().want, Self::env().account_id());
+        let locked = self.data::<StakeStorage>().total_stake
+            + self.data::<StakeStorage>().total_unstake
+            + self.data::<VestingStorage>().total_vesting;
+        if balance.saturating_sub(locked) < *amount {
+            return Err(VestingError::InsufficientFunds)
+        }
+        Ok(())
+    }
+
+    default fn _refund_vesting(&mut self, _amount: &Balance) -> Result<(), VestingError> {
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+use openbrush::{
+    storage::Mapping,
+    traits::Balance,
+};
+
+use crate::contracts_impls::{
+    stake::traits::MathError,
+    vesting::traits::{
+        VestingError,
+        VestingId,
+        VestingSchedule,
+    },
+};
+
+#[derive(Debug, Default)]
+#[openbrush::storage_item]
+pub struct VestingStorage {
+    pub next_vesting_id: VestingId,
+    pub schedules: Mapping<VestingId, VestingSchedule>,
+    pub total_vesting: Balance,
+}
+
+impl VestingStorage {
+    pub fn schedule_of(&self, vesting_id: &VestingId) -> Option<VestingSchedule> {
+        self.schedules.get(vesting_id)
+    }
+
+    pub fn add_schedule(&mut self, schedule: &VestingSchedule) -> Result<VestingId, VestingError> {
+        let vesting_id = self.next_vesting_id;
+        self.total_vesting = self.total_vesting.checked_add(schedule.amount).ok_or(MathError::Add)?;
+        self.schedules.insert(&vesting_id, schedule);
+        self.next_vesting_id = vesting_id.checked_add(1).ok_or(MathError::Add)?;
+        Ok(vesting_id)
+    }
+
+    pub fn decrease_total_vesting(&mut self, amount: &Balance) -> Result<(), VestingError> {
+        self.total_vesting = self.total_vesting.checked_sub(*amount).ok_or(MathError::Sub)?;
+        Ok(())
+    }
+}
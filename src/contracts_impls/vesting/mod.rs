@@ -0,0 +1,2 @@
+pub mod impls;
+pub mod traits;
@@ -0,0 +1,52 @@
+use openbrush::contracts::ownable::OwnableError;
+use scale::{
+    Decode,
+    Encode,
+};
+
+use crate::contracts_impls::{
+    govern::traits::GovernError,
+    stake::traits::{
+        MathError,
+        StakeError,
+    },
+};
+
+#[derive(Encode, Decode, Debug)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum VestingError {
+    StakeError(StakeError),
+    MathError(MathError),
+    OwnableError(OwnableError),
+    AmountIsZero,
+    WrongParameters,
+    InsufficientFunds,
+    NoSuchVesting,
+    CallerIsNotBeneficiary,
+    NothingToRelease,
+    GovernError(GovernError),
+}
+
+impl From<StakeError> for VestingError {
+    fn from(error: StakeError) -> Self {
+        VestingError::StakeError(error)
+    }
+}
+
+impl From<MathError> for VestingError {
+    fn from(error: MathError) -> Self {
+        VestingError::MathError(error)
+    }
+}
+
+impl From<OwnableError> for VestingError {
+    fn from(error: OwnableError) -> Self {
+        VestingError::OwnableError(error)
+    }
+}
+
+impl From<GovernError> for VestingError {
+    fn from(error: GovernError) -> Self {
+        VestingError::GovernError(error)
+    }
+}
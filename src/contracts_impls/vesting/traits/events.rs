@@ -0,0 +1,12 @@
+use openbrush::traits::Balance;
+
+use super::{
+    VestingId,
+    VestingSchedule,
+};
+
+pub trait EmitVestingEvents {
+    fn _emit_vesting_created_event(&self, vesting_id: &VestingId, schedule: &VestingSchedule);
+    fn _emit_vesting_released_event(&self, vesting_id: &VestingId, amount: &Balance);
+    fn _emit_vesting_revoked_event(&self, vesting_id: &VestingId, refunded: &Balance);
+}
@@ -0,0 +1,103 @@
+pub mod errors;
+pub mod events;
+pub mod structs;
+
+pub use errors::*;
+pub use events::*;
+pub use structs::*;
+
+use openbrush::traits::{
+    AccountId,
+    Balance,
+    Timestamp,
+};
+
+pub type VestingId = u32;
+
+#[openbrush::trait_definition]
+pub trait VestingView {
+    /// Returns Some(schedule) if the vesting identified by `vesting_id` exists and None otherwise.
+    #[ink(message)]
+    fn vesting(&self, vesting_id: VestingId) -> Option<VestingSchedule>;
+
+    /// Returns the amount that the beneficiary of `vesting_id` can release now.
+    #[ink(message)]
+    fn releasable(&self, vesting_id: VestingId) -> Balance;
+
+    /// Returns the number of created vestings. `VestingId`s are assigned from 0.
+    #[ink(message)]
+    fn number_of_vestings(&self) -> VestingId;
+
+    /// Returns total amount of `want` tokens that are vesting and haven't been yet released.
+    #[ink(message)]
+    fn total_vesting(&self) -> Balance;
+}
+
+#[openbrush::trait_definition]
+pub trait Vesting {
+    /// Creates the vesting of `amount` of `want` token to `beneficiary` starting at `start` and lasting `duration`.
+    /// Returns the id of created vesting.
+    ///
+    /// On Success emits `VestingCreated` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AmountIsZero` if `amount` is 0.
+    /// Returns `WrongParameters` if `duration` is 0.
+    /// Returns `InsufficientFunds` if the contract has not enough free `want` tokens to fund the vesting.
+    /// Returns wrapped `OwnableError` if the `caller` is not the `owner`.
+    #[ink(message)]
+    fn create_vesting(
+        &mut self,
+        beneficiary: AccountId,
+        amount: Balance,
+        start: Timestamp,
+        duration: Timestamp,
+        kind: VestingKind,
+    ) -> Result<VestingId, VestingError>;
+
+    /// Transfers the vested and not yet released part of `vesting_id` to `caller`. Returns the released amount.
+    ///
+    /// On Success emits `VestingReleased` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NoSuchVesting` if the vesting doesn't exist.
+    /// Returns `CallerIsNotBeneficiary` if `caller` is not the beneficiary of the vesting.
+    /// Returns `NothingToRelease` if nothing has vested since the last release.
+    /// Returns `StakeError` if the transfer failed.
+    #[ink(message)]
+    fn release(&mut self, vesting_id: VestingId) -> Result<Balance, VestingError>;
+
+    /// Revokes `vesting_id`. The vested part is transferred to the beneficiary and the rest is refunded.
+    ///
+    /// On Success emits `VestingRevoked` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NoSuchVesting` if the vesting doesn't exist.
+    /// Returns `StakeError` if the transfer failed.
+    /// Returns wrapped `OwnableError` if the `caller` is not the `owner`.
+    #[ink(message)]
+    fn revoke(&mut self, vesting_id: VestingId) -> Result<(), VestingError>;
+}
+
+pub trait VestingInternal {
+    /// Returns the amount of `schedule` vested at `timestamp`.
+    fn _vested_at(&self, schedule: &VestingSchedule, timestamp: &Timestamp) -> Balance;
+
+    /// Returns the amount of `schedule` that can be released at the current timestamp.
+    fn _releasable(&self, schedule: &VestingSchedule) -> Balance;
+}
+
+pub trait VestingFunding {
+    /// Reserves `amount` of `want` tokens held by the contract for a new vesting.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InsufficientFunds` if the contract has not enough free `want` tokens.
+    fn _fund_vesting(&mut self, amount: &Balance) -> Result<(), VestingError>;
+
+    /// Returns `amount` of `want` tokens of the revoked vesting to the free funds of the contract.
+    fn _refund_vesting(&mut self, amount: &Balance) -> Result<(), VestingError>;
+}
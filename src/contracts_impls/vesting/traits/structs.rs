@@ -0,0 +1,37 @@
+use openbrush::traits::{
+    AccountId,
+    Balance,
+    Timestamp,
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+/// Describes how the vesting amount becomes releasable.
+pub enum VestingKind {
+    /// amount vests linearly from `start` during `duration`.
+    Linear,
+    /// whole amount vests at `start` + `duration`.
+    Cliff,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+/// Represents `amount` of `want` token vested to `beneficiary`.
+pub struct VestingSchedule {
+    /// account that can release the vested tokens.
+    pub beneficiary: AccountId,
+    /// total amount of vested tokens.
+    pub amount: Balance,
+    /// amount already released by `beneficiary`.
+    pub released: Balance,
+    /// timestamp from which the vesting starts.
+    pub start: Timestamp,
+    /// time after `start` when the whole `amount` is vested.
+    pub duration: Timestamp,
+    /// how the `amount` vests.
+    pub kind: VestingKind,
+}
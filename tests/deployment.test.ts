@@ -4,9 +4,10 @@ import BN from 'bn.js';
 import Governor from '../typechain/contracts/governor';
 import GovernanceToken from 'typechain/contracts/governance_token';
 import { TestEnv, makeSuite } from './utlis/make-suite';
-import { BURNER, DAY, E12, E21, MINTER } from 'scripts/types_and_consts';
+import { BURNER, DAY, E12, E21, MINTER, STAKE_ADMIN } from 'scripts/types_and_consts';
 import { expect } from './setup/chai';
-import { deployGovernanceToken } from 'tests/setup/deploymentHelpers';
+import { defaultProposalRules, deployGovernanceToken, deployGovernor, deployStaker } from 'tests/setup/deploymentHelpers';
+import { FinalizationCurveBuilder } from 'typechain/types-returns/governor';
 
 makeSuite('Contract setup tests', (getTestEnv) => {
   let testEnv: TestEnv;
@@ -22,6 +23,21 @@ makeSuite('Contract setup tests', (getTestEnv) => {
     govToken = testEnv.govToken;
   });
 
+  describe('Deployment with wrong stake parameters...', () => {
+    it('of governor with unstake period smaller than minimal unstake period is rejected', async () => {
+      await expect(deployGovernor(deployer, govToken.address, 7 * DAY, DAY, 3, defaultProposalRules)).to.eventually.be.rejected;
+    });
+    it('of governor with 0 maximal number of unstakes is rejected', async () => {
+      await expect(deployGovernor(deployer, govToken.address, DAY, 7 * DAY, 0, defaultProposalRules)).to.eventually.be.rejected;
+    });
+    it('of staker with unstake period smaller than minimal unstake period is rejected', async () => {
+      await expect(deployStaker(deployer, govToken.address, 7 * DAY, DAY, 3)).to.eventually.be.rejected;
+    });
+    it('of staker with 0 maximal number of unstakes is rejected', async () => {
+      await expect(deployStaker(deployer, govToken.address, DAY, 7 * DAY, 0)).to.eventually.be.rejected;
+    });
+  });
+
   describe(`After deployment ...`, () => {
     describe('Governance token...', () => {
       describe('has properly set ...', () => {
@@ -85,6 +101,16 @@ makeSuite('Contract setup tests', (getTestEnv) => {
             expect(queryRes).to.be.equal(governor.address);
           });
         });
+        describe('access_control::Data', () => {
+          it('governor has STAKE_ADMIN role', async () => {
+            const queryRes = (await governor.query.hasRole(STAKE_ADMIN, governor.address)).value.ok!;
+            expect(queryRes).to.be.equal(true);
+          });
+          it('deployer has no STAKE_ADMIN role', async () => {
+            const queryRes = (await governor.query.hasRole(STAKE_ADMIN, deployer.address)).value.ok!;
+            expect(queryRes).to.be.equal(false);
+          });
+        });
         describe('StakeStorage', () => {
           it('want', async () => {
             const queryRes = (await governor.query.want()).value.ok!;
@@ -94,6 +120,10 @@ makeSuite('Contract setup tests', (getTestEnv) => {
             const queryRes = (await governor.query.unstakePeriod()).value.ok!;
             expect(queryRes).to.be.equal(21 * DAY);
           });
+          it('minimalUnstakePeriod', async () => {
+            const queryRes = (await governor.query.minimalUnstakePeriod()).value.ok!;
+            expect(queryRes).to.be.equal(7 * DAY);
+          });
           it('maximalNumberOfUnstakes', async () => {
             const queryRes = (await governor.query.maximalNumberOfUnstakes()).value.ok!;
             expect(queryRes).to.be.equal(3);
@@ -117,9 +147,18 @@ makeSuite('Contract setup tests', (getTestEnv) => {
               initialPeriod: 3 * DAY,
               flatPeriod: 7 * DAY,
               finalPeriod: 4 * DAY,
+              finalizationCurve: FinalizationCurveBuilder.Linear3Phase(),
               maximalVoterRewardPartE12: E12.divn(20).toNumber(),
               proposerSlashPartE12: E12.divn(2).toNumber(),
               voterSlashPartE12: E12.divn(5).toNumber(),
+              voterSlashGracePeriod: DAY,
+              slashBountyPartE12: E12.divn(10).toNumber(),
+              quorumPartE12: E12.divn(10).toNumber(),
+              ownerCancelReturnsDeposit: true,
+              executionDelay: 2 * DAY,
+              executionGracePeriod: 7 * DAY,
+              maximalVoteReasonLength: 256,
+              storeVoteReasons: true,
             });
           });
         });
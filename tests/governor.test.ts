@@ -1,3 +1,4 @@
+import { Keyring } from '@polkadot/keyring';
 import { KeyringPair } from '@polkadot/keyring/types';
 import BN from 'bn.js';
 // import BlockTimestampProviderContract from '../typechain/contracts/block_timestamp_provider';
@@ -7,8 +8,15 @@ import GovernanceToken from 'typechain/contracts/governance_token';
 import Governor from '../typechain/contracts/governor';
 import { TestEnv, makeSuite } from './utlis/make-suite';
 // import { GovernErrorBuilder, PSP22ErrorBuilder, StakeErrorBuilder } from 'typechain/types-returns/governor';
-import { Proposal, Vote } from 'typechain/types-arguments/governor';
-import { GovernErrorBuilder, ProposalStatus } from 'typechain/types-returns/governor';
+import { Proposal, TreasuryAsset, Vote } from 'typechain/types-arguments/governor';
+import {
+  FinalizationCurve,
+  FinalizationCurveBuilder,
+  GovernErrorBuilder,
+  OwnableErrorBuilder,
+  ProposalStatus,
+} from 'typechain/types-returns/governor';
+import { defaultProposalRules } from './setup/deploymentHelpers';
 import { apiProviderWrapper } from './setup/helpers';
 import { claimRewardAndCheck } from './utlis/checkers/governor/claimReward';
 import { executeAndCheck } from './utlis/checkers/governor/execute';
@@ -16,7 +24,7 @@ import { finalizeAndCheck } from './utlis/checkers/governor/finalize';
 import { proposeAndCheck } from './utlis/checkers/governor/propose';
 import { slashVoterAndCheck } from './utlis/checkers/governor/slashVoter';
 import { voteAndCheck } from './utlis/checkers/governor/vote';
-import { hexToNumbers } from './utlis/converters/hex-number';
+import { hexToNumbers, numbersToHex, paramsToInputNumbers } from './utlis/converters/hex-number';
 import { replaceRNPropsWithStrings, toE12 } from './utlis/misc';
 
 import type { WeightV2 } from '@polkadot/types/interfaces';
@@ -66,6 +74,8 @@ makeSuite('Governor tests', (getTestEnv) => {
     await governor.withSigner(users[3]).tx.stake(midStake);
     await governor.withSigner(users[4]).tx.stake(smallStake);
     await governor.withSigner(users[5]).tx.stake(smallStake);
+    // votes are taken just before the proposal start, so stakes count for proposals made later than them
+    await timestmpProvider.tx.increaseBlockTimestamp(1);
   });
   describe(' There is 6 stakers (user0,...,user5), with stake proportions 100,100,10,10,1,1', () => {
     describe('Proposing:', () => {
@@ -148,6 +158,33 @@ makeSuite('Governor tests', (getTestEnv) => {
         await proposeAndCheck(testEnv, users[0], proposal, description, undefined);
         await proposeAndCheck(testEnv, users[1], proposal, description, GovernErrorBuilder.ProposalAlreadyExists());
       });
+
+      it('user0 and user1 create proposals that can be enumerated', async () => {
+        const proposal0: Proposal = {
+          rulesId: 0,
+          voterRewardPartE12: toE12(0.05),
+          transactions: [],
+        };
+        const proposal1: Proposal = {
+          rulesId: 0,
+          voterRewardPartE12: toE12(0.01),
+          transactions: [],
+        };
+        await proposeAndCheck(testEnv, users[0], proposal0, 'Abax will be the best ;-)', undefined);
+        await proposeAndCheck(testEnv, users[1], proposal1, 'Abax will be the best ;-)', undefined);
+
+        const proposals = (await governor.query.proposals(0, 10)).value.ok!;
+        expect(proposals.length).to.be.equal(2);
+        expect(proposals[0][1].proposer.toString()).to.be.equal(users[0].address);
+        expect(proposals[1][1].proposer.toString()).to.be.equal(users[1].address);
+        expect((await governor.query.proposals(1, 10)).value.ok!.length).to.be.equal(1);
+        expect((await governor.query.proposals(2, 10)).value.ok!.length).to.be.equal(0);
+        expect((await governor.query.activeProposalIds(0, 1)).value.ok!.length).to.be.equal(1);
+        expect((await governor.query.activeProposalIds(0, 10)).value.ok!.length).to.be.equal(2);
+        const proposalsOfUser1 = (await governor.query.proposalsOfProposer(users[1].address, 0, 10)).value.ok!;
+        expect(proposalsOfUser1.length).to.be.equal(1);
+        expect(proposalsOfUser1[0][0].toString()).to.be.equal(proposals[1][0].toString());
+      });
     });
     describe('Voting', () => {
       const description = 'Abax will be the best ;-)';
@@ -173,9 +210,144 @@ makeSuite('Governor tests', (getTestEnv) => {
       it('user6 with no stake tries to vote', async () => {
         await voteAndCheck(testEnv, users[6], proposalId, Vote.agreed, GovernErrorBuilder.ZeroVotes());
       });
+      it('user6 stakes right after proposal was created, at the same timestamp, and tries to vote', async () => {
+        await governor.withSigner(users[6]).tx.stake(smallStake);
+        await voteAndCheck(testEnv, users[6], proposalId, Vote.agreed, GovernErrorBuilder.ZeroVotes());
+      });
+      it('user6 stakes after proposal was created and tries to vote', async () => {
+        await timestmpProvider.tx.increaseBlockTimestamp(1);
+        await governor.withSigner(users[6]).tx.stake(smallStake);
+        await voteAndCheck(testEnv, users[6], proposalId, Vote.agreed, GovernErrorBuilder.ZeroVotes());
+      });
       it('user0 tries to vote for not existing proposal', async () => {
         await voteAndCheck(testEnv, users[0], proposalId2, Vote.agreed, GovernErrorBuilder.ProposalDoesntExist());
       });
+      it('user1 votes in batch - the failing vote is returned and does not revert the successful one', async () => {
+        const queryRes = (
+          await governor.withSigner(users[1]).query.voteBatch([
+            [proposalId, Vote.agreed],
+            [proposalId2, Vote.agreed],
+          ])
+        ).value.ok;
+        expect(queryRes).to.deep.equal([{ ok: null }, { err: GovernErrorBuilder.ProposalDoesntExist() }]);
+        await governor.withSigner(users[1]).tx.voteBatch([
+          [proposalId, Vote.agreed],
+          [proposalId2, Vote.agreed],
+        ]);
+        expect((await governor.query.voteOfFor(users[1].address, proposalId)).value.ok?.vote).to.be.equal(Vote.agreed);
+      });
+      it('user0 tries to vote with too long reason', async () => {
+        await voteAndCheck(testEnv, users[0], proposalId, Vote.agreed, GovernErrorBuilder.VoteReasonTooLong(), Array(257).fill(1));
+      });
+      describe('vote signed off-chain with ecdsa', () => {
+        const sigDescription = 'Abax will be the best with signed votes ;-)';
+        const sigProposal: Proposal = {
+          rulesId: 0,
+          voterRewardPartE12: 0,
+          transactions: [],
+        };
+        const ecdsaKeyring = new Keyring({ type: 'ecdsa' });
+        const ecdsaVoter = ecdsaKeyring.addFromUri('//EcdsaVoter');
+        const ecdsaOther = ecdsaKeyring.addFromUri('//EcdsaOther');
+        let sigProposalId: number[];
+        const sign = async (signer: KeyringPair, nonce: number, deadline: number) => {
+          const payload = (await governor.query.voteBySigPayload(ecdsaVoter.address, sigProposalId, Vote.agreed, nonce, deadline)).value.ok!;
+          return { ecdsa: Array.from(signer.sign(hexToNumbers((payload as unknown as string).substring(2)))) };
+        };
+        beforeEach(async () => {
+          // the ecdsa voter gets its votes by delegation, so it never has to pay for a transaction
+          await governor.withSigner(users[6]).tx.stake(smallStake);
+          await governor.withSigner(users[6]).tx.delegate(ecdsaVoter.address);
+          await timestmpProvider.tx.increaseBlockTimestamp(1);
+          sigProposalId = hexToNumbers(
+            ((await testEnv.hasher.query.hashProposalWithDescription(sigProposal, sigDescription)).value.ok! as string).substring(2),
+          );
+          await proposeAndCheck(testEnv, users[1], sigProposal, sigDescription, undefined);
+        });
+        it('user1 submits the vote signed off-chain by the ecdsa voter and can not replay it', async () => {
+          const deadline = (await timestmpProvider.query.getBlockTimestamp()).value.ok! + DAY;
+          const signature = await sign(ecdsaVoter, 0, deadline);
+
+          const tx = governor.withSigner(users[1]).tx.voteBySig(ecdsaVoter.address, sigProposalId, Vote.agreed, 0, deadline, signature);
+          await expect(tx).to.eventually.be.fulfilled;
+          expect((await governor.query.voteOfFor(ecdsaVoter.address, sigProposalId)).value.ok?.vote).to.be.equal(Vote.agreed);
+          expect((await governor.query.voteOfFor(ecdsaVoter.address, sigProposalId)).value.ok?.amount.rawNumber.toString()).to.be.equal(
+            smallStake.toString(),
+          );
+          expect((await governor.query.voteNonceOf(ecdsaVoter.address)).value.ok).to.be.equal(1);
+
+          const queryRes = (
+            await governor.withSigner(users[1]).query.voteBySig(ecdsaVoter.address, sigProposalId, Vote.agreed, 0, deadline, signature)
+          ).value.ok;
+          expect(queryRes).to.have.deep.property('err', GovernErrorBuilder.InvalidNonce());
+        });
+        it('user1 tries to submit the vote of the ecdsa voter signed by other key', async () => {
+          const deadline = (await timestmpProvider.query.getBlockTimestamp()).value.ok! + DAY;
+          const signature = await sign(ecdsaOther, 0, deadline);
+          const queryRes = (
+            await governor.withSigner(users[1]).query.voteBySig(ecdsaVoter.address, sigProposalId, Vote.agreed, 0, deadline, signature)
+          ).value.ok;
+          expect(queryRes).to.have.deep.property('err', GovernErrorBuilder.InvalidSignature());
+        });
+        it('user1 tries to submit the vote signed by the ecdsa voter after the deadline', async () => {
+          const deadline = (await timestmpProvider.query.getBlockTimestamp()).value.ok! + DAY;
+          const signature = await sign(ecdsaVoter, 0, deadline);
+          await timestmpProvider.tx.increaseBlockTimestamp(DAY + 1);
+          const queryRes = (
+            await governor.withSigner(users[1]).query.voteBySig(ecdsaVoter.address, sigProposalId, Vote.agreed, 0, deadline, signature)
+          ).value.ok;
+          expect(queryRes).to.have.deep.property('err', GovernErrorBuilder.SignatureExpired());
+        });
+      });
+      describe('vote signed off-chain with sr25519', () => {
+        const sigDescription = 'Abax will be the best with sr25519 signed votes ;-)';
+        const sigProposal: Proposal = {
+          rulesId: 0,
+          voterRewardPartE12: 0,
+          transactions: [],
+        };
+        const sr25519Keyring = new Keyring({ type: 'sr25519' });
+        const sr25519Voter = sr25519Keyring.addFromUri('//Sr25519Voter');
+        const sr25519Other = sr25519Keyring.addFromUri('//Sr25519Other');
+        let sigProposalId: number[];
+        const sign = async (signer: KeyringPair, nonce: number, deadline: number) => {
+          const payload = (await governor.query.voteBySigPayload(sr25519Voter.address, sigProposalId, Vote.agreed, nonce, deadline)).value.ok!;
+          return { sr25519: Array.from(signer.sign(hexToNumbers((payload as unknown as string).substring(2)))) };
+        };
+        beforeEach(async () => {
+          await governor.withSigner(users[6]).tx.stake(smallStake);
+          await governor.withSigner(users[6]).tx.delegate(sr25519Voter.address);
+          await timestmpProvider.tx.increaseBlockTimestamp(1);
+          sigProposalId = hexToNumbers(
+            ((await testEnv.hasher.query.hashProposalWithDescription(sigProposal, sigDescription)).value.ok! as string).substring(2),
+          );
+          await proposeAndCheck(testEnv, users[1], sigProposal, sigDescription, undefined);
+        });
+        it('user1 submits the vote signed off-chain by the sr25519 voter', async () => {
+          const deadline = (await timestmpProvider.query.getBlockTimestamp()).value.ok! + DAY;
+          const signature = await sign(sr25519Voter, 0, deadline);
+
+          const tx = governor.withSigner(users[1]).tx.voteBySig(sr25519Voter.address, sigProposalId, Vote.agreed, 0, deadline, signature);
+          await expect(tx).to.eventually.be.fulfilled;
+          expect((await governor.query.voteOfFor(sr25519Voter.address, sigProposalId)).value.ok?.amount.rawNumber.toString()).to.be.equal(
+            smallStake.toString(),
+          );
+          expect((await governor.query.voteNonceOf(sr25519Voter.address)).value.ok).to.be.equal(1);
+        });
+        it('user1 tries to submit the vote of the sr25519 voter signed by other key', async () => {
+          const deadline = (await timestmpProvider.query.getBlockTimestamp()).value.ok! + DAY;
+          const signature = await sign(sr25519Other, 0, deadline);
+          const queryRes = (
+            await governor.withSigner(users[1]).query.voteBySig(sr25519Voter.address, sigProposalId, Vote.agreed, 0, deadline, signature)
+          ).value.ok;
+          expect(queryRes).to.have.deep.property('err', GovernErrorBuilder.InvalidSignature());
+        });
+      });
+      it('user0 votes with reason that is stored', async () => {
+        const reason = Array.from(encoder.encode('Abax will be the best'));
+        await voteAndCheck(testEnv, users[0], proposalId, Vote.agreed, undefined, reason);
+        expect((await governor.query.voteReasonOf(users[0].address, proposalId)).value.ok).to.be.equal('0x' + numbersToHex(reason));
+      });
       it('user0 tries to vote after prposal is finalized ', async () => {
         await testEnv.timestampProvider.tx.increaseBlockTimestamp(22 * DAY);
         await governor.tx.finalize(proposalId);
@@ -189,6 +361,110 @@ makeSuite('Governor tests', (getTestEnv) => {
         await voteAndCheck(testEnv, users[4], proposalId, Vote.disagreedWithProposerSlashing);
       });
     });
+    describe('Delegation', () => {
+      const description = 'Abax will be the best ;-)';
+      const proposal: Proposal = {
+        rulesId: 0,
+        voterRewardPartE12: 0,
+        transactions: [],
+      };
+      let proposalId: number[];
+      beforeEach(async () => {
+        proposalId = hexToNumbers(((await testEnv.hasher.query.hashProposalWithDescription(proposal, description)).value.ok! as string).substring(2));
+        await governor.withSigner(users[1]).tx.delegate(users[0].address);
+        await timestmpProvider.tx.increaseBlockTimestamp(1);
+        await proposeAndCheck(testEnv, users[0], proposal, description, undefined);
+      });
+      it('user1 tries to delegate to himself', async () => {
+        const queryRes = (await governor.withSigner(users[1]).query.delegate(users[1].address)).value.ok;
+        expect(queryRes).to.have.deep.property('err', GovernErrorBuilder.SelfDelegation());
+      });
+      it('user1 who has delegated tries to vote', async () => {
+        await voteAndCheck(testEnv, users[1], proposalId, Vote.agreed, GovernErrorBuilder.ZeroVotes());
+      });
+      it('user0 votes with own and delegated stake', async () => {
+        expect((await governor.query.delegateOf(users[1].address)).value.ok).to.be.equal(users[0].address);
+        expect((await governor.query.delegatedPowerOf(users[0].address)).value.ok!.rawNumber.toString()).to.be.equal(bigStake.toString());
+        await governor.withSigner(users[0]).tx.vote(proposalId, Vote.agreed, []);
+        const voteOf = (await governor.query.voteOfFor(users[0].address, proposalId)).value.ok!;
+        expect(voteOf.amount.rawNumber.toString()).to.be.equal(bigStake.muln(2).toString());
+      });
+      it('user1 undelegates but still can not vote on proposal created before', async () => {
+        await governor.withSigner(users[1]).tx.undelegate();
+        expect((await governor.query.delegateOf(users[1].address)).value.ok).to.be.null;
+        await voteAndCheck(testEnv, users[1], proposalId, Vote.agreed, GovernErrorBuilder.ZeroVotes());
+      });
+    });
+    describe('Rules update', () => {
+      const description = 'Abax will be the best ;-)';
+      const proposal: Proposal = {
+        rulesId: 0,
+        voterRewardPartE12: 0,
+        transactions: [],
+      };
+      let proposalId: number[];
+      beforeEach(async () => {
+        proposalId = hexToNumbers(((await testEnv.hasher.query.hashProposalWithDescription(proposal, description)).value.ok! as string).substring(2));
+        await proposeAndCheck(testEnv, users[0], proposal, description, undefined);
+      });
+      it('user0 who is not the owner tries to update rules', async () => {
+        const queryRes = (await governor.withSigner(users[0]).query.updateRules(0, { ...defaultProposalRules, flatPeriod: DAY })).value.ok;
+        expect(queryRes).to.have.deep.property('err', GovernErrorBuilder.OwnableError(OwnableErrorBuilder.CallerIsNotOwner()));
+      });
+      it('owner tries to update rules with zero final period', async () => {
+        const queryRes = (await governor.withSigner(deployer).query.updateRules(0, { ...defaultProposalRules, finalPeriod: 0 })).value.ok;
+        expect(queryRes).to.have.deep.property('err', GovernErrorBuilder.WrongParameters());
+      });
+      it('owner tries to update rules that dont exist', async () => {
+        const queryRes = (await governor.withSigner(deployer).query.updateRules(7, defaultProposalRules)).value.ok;
+        expect(queryRes).to.have.deep.property('err', GovernErrorBuilder.NoSuchRule());
+      });
+      it('owner updates rules. Existing proposal keeps the old version and new proposal uses the new one', async () => {
+        const tx = governor.withSigner(deployer).tx.updateRules(0, { ...defaultProposalRules, flatPeriod: DAY });
+        await expect(tx).to.eventually.be.fulfilled;
+        const txRes = await tx;
+        expect(txRes.events?.[0].name).to.be.equal('ProposalRulesUpdated');
+
+        const history = (await governor.query.rulesHistory(0)).value.ok!;
+        expect(history.length).to.be.equal(2);
+        expect(history[1].flatPeriod).to.be.equal(DAY);
+        expect((await governor.query.rules(0)).value.ok!.flatPeriod).to.be.equal(DAY);
+        expect((await governor.query.state(proposalId)).value.ok!.rulesVersion).to.be.equal(0);
+
+        await proposeAndCheck(testEnv, users[1], { ...proposal, voterRewardPartE12: 1 }, description, undefined);
+      });
+    });
+    describe('Cancel', () => {
+      const description = 'Abax will be the best ;-)';
+      const proposal: Proposal = {
+        rulesId: 0,
+        voterRewardPartE12: 0,
+        transactions: [],
+      };
+      let proposalId: number[];
+      beforeEach(async () => {
+        proposalId = hexToNumbers(((await testEnv.hasher.query.hashProposalWithDescription(proposal, description)).value.ok! as string).substring(2));
+        await proposeAndCheck(testEnv, users[0], proposal, description, undefined);
+      });
+      it('user1 who is neither the proposer nor the owner tries to cancel', async () => {
+        const queryRes = (await governor.withSigner(users[1]).query.cancel(proposalId)).value.ok;
+        expect(queryRes).to.have.deep.property('err', GovernErrorBuilder.OwnableError(OwnableErrorBuilder.CallerIsNotOwner()));
+      });
+      it('user0 tries to cancel after votes were cast', async () => {
+        await governor.withSigner(users[1]).tx.vote(proposalId, Vote.agreed, []);
+        const queryRes = (await governor.withSigner(users[0]).query.cancel(proposalId)).value.ok;
+        expect(queryRes).to.have.deep.property('err', GovernErrorBuilder.CancelCondition());
+      });
+      it('user0 cancels before any vote was cast', async () => {
+        const activeProposalsBefore = (await governor.query.activeProposals()).value.ok!;
+        const tx = governor.withSigner(users[0]).tx.cancel(proposalId);
+        await expect(tx).to.eventually.be.fulfilled;
+        expect((await governor.query.status(proposalId)).value.ok).to.be.equal(ProposalStatus.cancelled);
+        expect((await governor.query.activeProposals()).value.ok).to.be.equal(activeProposalsBefore - 1);
+        expect((await governor.query.activeProposalIds(0, 10)).value.ok).to.be.empty;
+        await voteAndCheck(testEnv, users[1], proposalId, Vote.agreed, GovernErrorBuilder.NotActive());
+      });
+    });
     describe('Finalize', () => {
       const description = 'Abax will be the best ;-)';
       const proposal: Proposal = {
@@ -216,6 +492,70 @@ makeSuite('Governor tests', (getTestEnv) => {
       it('user tries to finalize proposal that doesnt meet finalization condition', async () => {
         await finalizeAndCheck(testEnv, users[6], proposalId, undefined, GovernErrorBuilder.FinalizeCondition());
       });
+      it('minimum to finalize is sampled from the Linear3Phase curve at any timestamp', async () => {
+        const state = (await governor.query.state(proposalId)).value.ok!;
+        const start = state.start;
+        const totalVotes = state.votesAtStart.rawNumber;
+        const minimumAt = async (timestamp: number) => (await governor.query.minimumToFinalizeAt(proposalId, timestamp)).value.ok!.ok!.rawNumber.toString();
+        expect(await minimumAt(start)).to.be.equal(totalVotes.toString());
+        expect(await minimumAt(start + 3 * DAY)).to.be.equal(totalVotes.divn(2).toString());
+        expect(await minimumAt(start + 10 * DAY)).to.be.equal(totalVotes.divn(2).toString());
+        expect(await minimumAt(start + 14 * DAY + 1)).to.be.equal('0');
+      });
+      describe('proposal with rules with other finalization curve', () => {
+        const curveDescription = 'Abax will be the best with other curve ;-)';
+        const curveProposal: Proposal = {
+          rulesId: 1,
+          voterRewardPartE12: 0,
+          transactions: [],
+        };
+        let curveProposalId: number[];
+        let start: number;
+        let totalVotes: BN;
+        const minimumAt = async (timestamp: number) =>
+          (await governor.query.minimumToFinalizeAt(curveProposalId, timestamp)).value.ok!.ok!.rawNumber.toString();
+        const proposeWithCurve = async (finalizationCurve: FinalizationCurve) => {
+          await governor.withSigner(deployer).tx.addProposalRules({ ...defaultProposalRules, finalizationCurve });
+          await governor.withSigner(deployer).tx.allowRules(1, true);
+          curveProposalId = hexToNumbers(
+            ((await testEnv.hasher.query.hashProposalWithDescription(curveProposal, curveDescription)).value.ok! as string).substring(2),
+          );
+          await proposeAndCheck(testEnv, users[1], curveProposal, curveDescription, undefined);
+          const state = (await governor.query.state(curveProposalId)).value.ok!;
+          start = state.start;
+          totalVotes = state.votesAtStart.rawNumber;
+        };
+        it('minimum to finalize is sampled from the ExponentialDecay curve at any timestamp', async () => {
+          await proposeWithCurve(FinalizationCurveBuilder.ExponentialDecay(DAY));
+          expect(await minimumAt(start)).to.be.equal(totalVotes.toString());
+          expect(await minimumAt(start + DAY / 2)).to.be.equal(totalVotes.sub(totalVotes.divn(2).muln(DAY / 2).divn(DAY)).toString());
+          expect(await minimumAt(start + DAY)).to.be.equal(totalVotes.shrn(1).toString());
+          expect(await minimumAt(start + 3 * DAY)).to.be.equal(totalVotes.shrn(3).toString());
+          expect(await minimumAt(start + 14 * DAY + 1)).to.be.equal('0');
+        });
+        it('minimum to finalize is sampled from the FixedMajority curve at any timestamp', async () => {
+          await proposeWithCurve(FinalizationCurveBuilder.FixedMajority(toE12(0.6)));
+          expect(await minimumAt(start)).to.be.equal(totalVotes.muln(6).divn(10).toString());
+          expect(await minimumAt(start + 10 * DAY)).to.be.equal(totalVotes.muln(6).divn(10).toString());
+          expect(await minimumAt(start + 14 * DAY + 1)).to.be.equal('0');
+        });
+        it('with FixedMajority curve votes against below the part dont defeat the proposal', async () => {
+          await proposeWithCurve(FinalizationCurveBuilder.FixedMajority(toE12(0.6)));
+          await voteAndCheck(testEnv, users[0], curveProposalId, Vote.disagreed);
+          await finalizeAndCheck(testEnv, users[0], curveProposalId, undefined, GovernErrorBuilder.FinalizeCondition());
+        });
+        it('minimum to finalize is sampled from the Supermajority curve at any timestamp', async () => {
+          await proposeWithCurve(FinalizationCurveBuilder.Supermajority(toE12(0.6)));
+          expect(await minimumAt(start)).to.be.equal(totalVotes.muln(6).divn(10).toString());
+          expect(await minimumAt(start + 10 * DAY)).to.be.equal(totalVotes.muln(6).divn(10).toString());
+          expect(await minimumAt(start + 14 * DAY + 1)).to.be.equal('0');
+        });
+        it('with Supermajority curve votes against that make the part unreachable defeat the proposal', async () => {
+          await proposeWithCurve(FinalizationCurveBuilder.Supermajority(toE12(0.6)));
+          await voteAndCheck(testEnv, users[0], curveProposalId, Vote.disagreed);
+          await finalizeAndCheck(testEnv, users[0], curveProposalId, ProposalStatus.defeated);
+        });
+      });
       describe(`all stakers votes for 'agree`, () => {
         beforeEach(async () => {
           await voteAndCheck(testEnv, users[0], proposalId, Vote.agreed);
@@ -254,6 +594,17 @@ makeSuite('Governor tests', (getTestEnv) => {
         it('user finalize succesfully', async () => {
           await finalizeAndCheck(testEnv, users[0], proposalId, ProposalStatus.defeatedWithSlash);
         });
+        it('slashed stake of the proposer is recorded in the treasury and can not be spent outside of a proposal', async () => {
+          const stakeBefore = (await governor.query.stakeOf(users[0].address)).value.ok!.rawNumber;
+          await finalizeAndCheck(testEnv, users[0], proposalId, ProposalStatus.defeatedWithSlash);
+          const stakeAfter = (await governor.query.stakeOf(users[0].address)).value.ok!.rawNumber;
+          const treasury = (await governor.query.treasuryBalance()).value.ok!;
+          expect(treasury.want.rawNumber.toString()).to.be.equal(stakeBefore.sub(stakeAfter).toString());
+          expect(treasury.native.rawNumber.toString()).to.be.equal('0');
+
+          const queryRes = (await governor.withSigner(users[0]).query.treasuryTransfer(TreasuryAsset.want, users[0].address, treasury.want)).value.ok;
+          expect(queryRes).to.have.deep.property('err', GovernErrorBuilder.OwnableError(OwnableErrorBuilder.CallerIsNotOwner()));
+        });
       });
       describe(`all stakers votes for disagree or disagreedWithProposerSlashing, but most for disagreed`, () => {
         beforeEach(async () => {
@@ -344,6 +695,23 @@ makeSuite('Governor tests', (getTestEnv) => {
           });
         });
       });
+      describe(`only small % votes for agree, user1 abstains, rest didnt vote`, () => {
+        beforeEach(async () => {
+          await voteAndCheck(testEnv, users[5], proposalId, Vote.agreed);
+          await voteAndCheck(testEnv, users[1], proposalId, Vote.abstained);
+        });
+        it('user tries to finalize that doesnt meet finalization condition as abstain votes are neither for nor against', async () => {
+          await finalizeAndCheck(testEnv, users[0], proposalId, undefined, GovernErrorBuilder.FinalizeCondition());
+        });
+        describe(`then 14 days minus 1 second passes`, () => {
+          beforeEach(async () => {
+            await timestmpProvider.tx.increaseBlockTimestamp(14 * DAY - 1);
+          });
+          it('user finalize succesfully as the quorum is reached with abstain votes', async () => {
+            await finalizeAndCheck(testEnv, users[0], proposalId, ProposalStatus.succeeded);
+          });
+        });
+      });
       describe(`only small % votes for agree, rest didnt vote`, () => {
         beforeEach(async () => {
           await voteAndCheck(testEnv, users[5], proposalId, Vote.agreed);
@@ -369,8 +737,17 @@ makeSuite('Governor tests', (getTestEnv) => {
               beforeEach(async () => {
                 await timestmpProvider.tx.increaseBlockTimestamp(4 * DAY - 1);
               });
-              it('user finalize succesfully as in final period treshold goes to 0 is reached', async () => {
-                await finalizeAndCheck(testEnv, users[0], proposalId, ProposalStatus.succeeded);
+              it('user tries to finalize that doesnt reach the quorum', async () => {
+                await finalizeAndCheck(testEnv, users[0], proposalId, undefined, GovernErrorBuilder.FinalizeCondition());
+              });
+              describe(`then 2 second passes`, () => {
+                beforeEach(async () => {
+                  await timestmpProvider.tx.increaseBlockTimestamp(2);
+                });
+                it('proposal is Expired as the quorum was not reached and user finalize it with Expired', async () => {
+                  expect((await governor.query.status(proposalId)).value.ok).to.be.equal(ProposalStatus.expired);
+                  await finalizeAndCheck(testEnv, users[0], proposalId, ProposalStatus.expired);
+                });
               });
             });
           });
@@ -401,8 +778,16 @@ makeSuite('Governor tests', (getTestEnv) => {
               beforeEach(async () => {
                 await timestmpProvider.tx.increaseBlockTimestamp(4 * DAY - 1);
               });
-              it('user finalize succesfully as in final period treshold goes to 0 is reached', async () => {
-                await finalizeAndCheck(testEnv, users[0], proposalId, ProposalStatus.defeated);
+              it('user tries to finalize that doesnt reach the quorum although in final period treshold goes to 0', async () => {
+                await finalizeAndCheck(testEnv, users[0], proposalId, undefined, GovernErrorBuilder.FinalizeCondition());
+              });
+              describe(`then 2 second passes`, () => {
+                beforeEach(async () => {
+                  await timestmpProvider.tx.increaseBlockTimestamp(2);
+                });
+                it('user finalize with Expired as the quorum was not reached', async () => {
+                  await finalizeAndCheck(testEnv, users[0], proposalId, ProposalStatus.expired);
+                });
               });
             });
           });
@@ -433,8 +818,16 @@ makeSuite('Governor tests', (getTestEnv) => {
               beforeEach(async () => {
                 await timestmpProvider.tx.increaseBlockTimestamp(4 * DAY - 1);
               });
-              it('user finalize succesfully as in final period treshold goes to 0 is reached', async () => {
-                await finalizeAndCheck(testEnv, users[0], proposalId, ProposalStatus.defeatedWithSlash);
+              it('user tries to finalize that doesnt reach the quorum although in final period treshold goes to 0', async () => {
+                await finalizeAndCheck(testEnv, users[0], proposalId, undefined, GovernErrorBuilder.FinalizeCondition());
+              });
+              describe(`then 2 second passes`, () => {
+                beforeEach(async () => {
+                  await timestmpProvider.tx.increaseBlockTimestamp(2);
+                });
+                it('user finalize with Expired as the quorum was not reached', async () => {
+                  await finalizeAndCheck(testEnv, users[0], proposalId, ProposalStatus.expired);
+                });
               });
             });
           });
@@ -469,8 +862,8 @@ makeSuite('Governor tests', (getTestEnv) => {
                 beforeEach(async () => {
                   await timestmpProvider.tx.increaseBlockTimestamp(2);
                 });
-                it('user tries to finalize that doesnt meet finalization condition', async () => {
-                  await finalizeAndCheck(testEnv, users[0], proposalId, ProposalStatus.defeated);
+                it('user finalize with Expired as the quorum was not reached', async () => {
+                  await finalizeAndCheck(testEnv, users[0], proposalId, ProposalStatus.expired);
                 });
               });
             });
@@ -539,6 +932,48 @@ makeSuite('Governor tests', (getTestEnv) => {
           await slashVoterAndCheck(testEnv, users[0], users[1].address, proposalId);
           await slashVoterAndCheck(testEnv, users[0], users[1].address, proposalId, GovernErrorBuilder.AlreadyClaimedOrSlashed());
         });
+        it(`user6 slashes in bulk - ineligible accounts are skipped and user6 receives the bounty`, async () => {
+          const balanceBefore = (await govToken.query.balanceOf(users[6].address)).value.ok!.rawNumber;
+          const tx = governor.withSigner(users[6]).tx.slashVoters(proposalId, [users[0].address, users[1].address, users[2].address]);
+          await expect(tx).to.eventually.be.fulfilled;
+          const results = (await tx).value.ok!.ok!;
+          expect(results[0]).to.deep.equal({ err: GovernErrorBuilder.Voted() });
+          const slashed = results[1].ok!.rawNumber.add(results[2].ok!.rawNumber);
+          const balanceAfter = (await govToken.query.balanceOf(users[6].address)).value.ok!.rawNumber;
+          const bounty = slashed.mul(new BN(defaultProposalRules.slashBountyPartE12.toString())).div(E12);
+          expect(balanceAfter.sub(balanceBefore).toString()).to.be.equal(bounty.toString());
+          expect((await governor.query.treasuryBalance()).value.ok!.want.rawNumber.toString()).to.be.equal(slashed.sub(bounty).toString());
+          expect((await governor.query.claimedOrSlashed(users[1].address, proposalId)).value.ok).to.be.true;
+        });
+        it(`isSlashable returns the penalty of user1 and the reason user0 can not be slashed`, async () => {
+          const penalty = (await governor.query.isSlashable(users[1].address, proposalId)).value.ok!.ok!.rawNumber;
+          const tx = governor.withSigner(users[6]).tx.slashVoters(proposalId, [users[1].address]);
+          await expect(tx).to.eventually.be.fulfilled;
+          expect((await tx).value.ok!.ok![0].ok!.rawNumber.toString()).to.be.equal(penalty.toString());
+          expect((await governor.query.isSlashable(users[0].address, proposalId)).value.ok!.err).to.deep.equal(GovernErrorBuilder.Voted());
+          expect((await governor.query.isSlashable(users[1].address, proposalId)).value.ok!.err).to.deep.equal(
+            GovernErrorBuilder.AlreadyClaimedOrSlashed(),
+          );
+        });
+        it(`user6 who had no stake at the proposal start is not slashable and is skipped when slashing in bulk`, async () => {
+          expect((await governor.query.isSlashable(users[6].address, proposalId)).value.ok!.err).to.deep.equal(GovernErrorBuilder.NothingToSlash());
+          const tx = governor.withSigner(users[0]).tx.slashVoters(proposalId, [users[6].address]);
+          await expect(tx).to.eventually.be.fulfilled;
+          expect((await tx).value.ok!.ok![0]).to.deep.equal({ err: GovernErrorBuilder.NothingToSlash() });
+          expect((await governor.query.claimedOrSlashed(users[6].address, proposalId)).value.ok).to.be.false;
+        });
+      });
+      describe(`proposal is finalized in final period and user1 abstained`, () => {
+        beforeEach(async () => {
+          await governor.withSigner(users[0]).tx.vote(proposalId, Vote.agreed, []);
+          await governor.withSigner(users[1]).tx.vote(proposalId, Vote.abstained, []);
+
+          await timestmpProvider.tx.increaseBlockTimestamp(12 * DAY);
+          await governor.tx.finalize(proposalId);
+        });
+        it(`someone tries to slash user1 who has abstained`, async () => {
+          await slashVoterAndCheck(testEnv, users[0], users[1].address, proposalId, GovernErrorBuilder.Voted());
+        });
       });
       describe(`proposal is finalized in final period and user6 has staken 0.5 DAY before finalization and hasnt voted `, () => {
         beforeEach(async () => {
@@ -611,6 +1046,19 @@ makeSuite('Governor tests', (getTestEnv) => {
           await claimRewardAndCheck(testEnv, users[2], proposalId);
           await claimRewardAndCheck(testEnv, users[3], proposalId);
         });
+        it(`user0 claims in batch - the failing claims are returned and don't revert the successful one`, async () => {
+          const stakeBefore = (await governor.query.stakeOf(users[0].address)).value.ok!.rawNumber;
+          const tx = governor.withSigner(users[0]).tx.claimRewards([proposalId, proposalId2, proposalId]);
+          await expect(tx).to.eventually.be.fulfilled;
+          const txRes = await tx;
+          expect(txRes.value.ok?.ok).to.deep.equal([
+            { ok: null },
+            { err: GovernErrorBuilder.ProposalDoesntExist() },
+            { err: GovernErrorBuilder.AlreadyClaimedOrSlashed() },
+          ]);
+          expect((await governor.query.stakeOf(users[0].address)).value.ok!.rawNumber.gt(stakeBefore)).to.be.true;
+          expect((await governor.query.claimedOrSlashed(users[0].address, proposalId)).value.ok).to.be.true;
+        });
       });
     });
     describe('Execute', () => {
@@ -677,8 +1125,25 @@ makeSuite('Governor tests', (getTestEnv) => {
           await timestmpProvider.tx.increaseBlockTimestamp(9 * DAY);
           await governor.tx.finalize(proposalId);
         });
-        it('user0 executes Succeded proposal with no Tx', async () => {
-          await executeAndCheck(testEnv, users[0], proposal, descriptionHash, description);
+        it('user0 tries to execute Succeded proposal that was not queued', async () => {
+          await executeAndCheck(testEnv, users[0], proposal, descriptionHash, description, GovernErrorBuilder.WrongStatus());
+        });
+        describe(`then proposal is queued`, () => {
+          beforeEach(async () => {
+            await governor.tx.queue(proposalId);
+          });
+          it('user0 tries to execute Queued proposal before eta', async () => {
+            await executeAndCheck(testEnv, users[0], proposal, descriptionHash, description, GovernErrorBuilder.TimelockNotPassed());
+          });
+          it('user0 tries to execute Queued proposal after grace period', async () => {
+            await timestmpProvider.tx.increaseBlockTimestamp(9 * DAY + 1);
+            expect((await governor.query.status(proposalId)).value.ok).to.be.equal(ProposalStatus.expired);
+            await executeAndCheck(testEnv, users[0], proposal, descriptionHash, description, GovernErrorBuilder.ProposalExpired());
+          });
+          it('user0 executes Queued proposal with no Tx after eta', async () => {
+            await timestmpProvider.tx.increaseBlockTimestamp(2 * DAY);
+            await executeAndCheck(testEnv, users[0], proposal, descriptionHash, description);
+          });
         });
       });
     });
@@ -707,18 +1172,24 @@ makeSuite('Governor tests', (getTestEnv) => {
               selector: params1.selector,
               input: params1.data,
               transferredValue: 0,
+              gasLimit: null,
+              allowFailure: false,
             },
             {
               callee: govToken.address,
               selector: params2.selector,
               input: params2.data,
               transferredValue: 0,
+              gasLimit: null,
+              allowFailure: false,
             },
             {
               callee: govToken.address,
               selector: params3.selector,
               input: params3.data,
               transferredValue: 0,
+              gasLimit: null,
+              allowFailure: false,
             },
           ],
         };
@@ -735,8 +1206,10 @@ makeSuite('Governor tests', (getTestEnv) => {
 
           await timestmpProvider.tx.increaseBlockTimestamp(9 * DAY);
           await governor.tx.finalize(proposalId);
+          await governor.tx.queue(proposalId);
+          await timestmpProvider.tx.increaseBlockTimestamp(2 * DAY);
         });
-        it('user0 executes Succeded proposal with no Tx', async () => {
+        it('user0 executes Queued proposal with Txs', async () => {
           await executeAndCheck(testEnv, users[0], proposal, descriptionHash, description);
 
           expect((await govToken.query.allowance(governor.address, users[0].address)).value.ok!.rawNumber.toString()).to.be.equal(E12.toString());
@@ -747,6 +1220,79 @@ makeSuite('Governor tests', (getTestEnv) => {
             E12.muln(3).toString(),
           );
         });
+        it('user0 executes Queued proposal with Txs by id', async () => {
+          expect((await governor.query.proposal(proposalId)).value.ok!.transactions.length).to.be.equal(3);
+          const tx = governor.withSigner(users[0]).tx.executeById(proposalId);
+          await expect(tx).to.eventually.be.fulfilled;
+
+          expect((await governor.query.status(proposalId)).value.ok).to.be.equal(ProposalStatus.executed);
+          expect((await govToken.query.allowance(governor.address, users[0].address)).value.ok!.rawNumber.toString()).to.be.equal(E12.toString());
+        });
+      });
+    });
+    describe('Execute Proposal with reverting transaction', () => {
+      const description = 'Abax will transfer more than it has';
+      let proposal: Proposal;
+      let proposalId: number[];
+      let descriptionHash: number[];
+
+      const proposeWithRevertingTx = async (allowFailure: boolean, callee2?: string) => {
+        const params1 = paramsToInputNumbers(govToken.abi.findMessage('PSP22::increase_allowance').toU8a([users[0].address, E12.toString()]));
+        // governor holds less than E21 so the transfer fails with InsufficientBalance
+        const params2 = paramsToInputNumbers(govToken.abi.findMessage('PSP22::transfer').toU8a([users[1].address, E21.toString(), []]));
+        proposal = {
+          rulesId: 0,
+          voterRewardPartE12: toE12(0.001),
+          transactions: [
+            {
+              callee: govToken.address,
+              selector: params1.selector,
+              input: params1.data,
+              transferredValue: 0,
+              gasLimit: null,
+              allowFailure: false,
+            },
+            {
+              // by default the transfer is sent to an account with no contract, so the call fails
+              callee: callee2 ?? users[5].address,
+              selector: params2.selector,
+              input: params2.data,
+              transferredValue: 0,
+              gasLimit: null,
+              allowFailure: allowFailure,
+            },
+          ],
+        };
+        proposalId = hexToNumbers(((await testEnv.hasher.query.hashProposalWithDescription(proposal, description)).value.ok! as string).substring(2));
+        descriptionHash = (await testEnv.hasher.query.hashDescription(description)).value.ok!;
+        await proposeAndCheck(testEnv, users[0], proposal, description, undefined);
+
+        await governor.withSigner(users[0]).tx.vote(proposalId, Vote.agreed, []);
+        await governor.withSigner(users[2]).tx.vote(proposalId, Vote.agreed, []);
+        await governor.withSigner(users[3]).tx.vote(proposalId, Vote.agreed, []);
+        await timestmpProvider.tx.increaseBlockTimestamp(9 * DAY);
+        await governor.tx.finalize(proposalId);
+        await governor.tx.queue(proposalId);
+        await timestmpProvider.tx.increaseBlockTimestamp(2 * DAY);
+      };
+
+      it('user0 tries to execute proposal with reverting Tx that does not allow failure', async () => {
+        await proposeWithRevertingTx(false);
+        await executeAndCheck(testEnv, users[0], proposal, descriptionHash, description, GovernErrorBuilder.UnderlyingTransactionReverted(1));
+        expect((await governor.query.status(proposalId)).value.ok).to.be.equal(ProposalStatus.queued);
+        expect((await govToken.query.allowance(governor.address, users[0].address)).value.ok!.rawNumber.toString()).to.be.equal('0');
+      });
+      it('user0 executes proposal with reverting Tx that allows failure and the Tx is recorded as Reverted', async () => {
+        await proposeWithRevertingTx(true);
+        await executeAndCheck(testEnv, users[0], proposal, descriptionHash, description, undefined, [{ succeeded: '0x00' }, { reverted: null }]);
+        expect((await govToken.query.allowance(governor.address, users[0].address)).value.ok!.rawNumber.toString()).to.be.equal(E12.toString());
+        expect((await govToken.query.balanceOf(users[1].address)).value.ok!.rawNumber.toString()).to.be.equal('0');
+      });
+      it('user0 executes proposal with Tx whose message returns an error and the Tx is recorded as Succeeded with the encoded error', async () => {
+        await proposeWithRevertingTx(false, govToken.address);
+        // Err(PSP22Error::InsufficientBalance)
+        await executeAndCheck(testEnv, users[0], proposal, descriptionHash, description, undefined, [{ succeeded: '0x00' }, { succeeded: '0x0101' }]);
+        expect((await govToken.query.balanceOf(users[1].address)).value.ok!.rawNumber.toString()).to.be.equal('0');
       });
     });
   });
@@ -773,17 +1319,3 @@ makeSuite('Governor tests', (getTestEnv) => {
     });
   });
 });
-
-function paramsToInputNumbers(params1: Uint8Array) {
-  let ecdStr = '';
-  for (let i = 1; i < params1.length; ++i) {
-    let stemp = params1[i].toString(16);
-    if (stemp.length < 2) {
-      stemp = '0' + stemp;
-    }
-    ecdStr += stemp;
-  }
-  const selector = hexToNumbers(ecdStr.substring(0, 8));
-  const data = hexToNumbers(ecdStr.substring(8));
-  return { selector, data };
-}
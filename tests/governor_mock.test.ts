@@ -0,0 +1,203 @@
+import { KeyringPair } from '@polkadot/keyring/types';
+import { DAY, E12, E21, E6 } from 'scripts/types_and_consts';
+import BlockTimestsampProvider from 'typechain/contracts/block_timestamp_provider';
+import GovernanceToken from 'typechain/contracts/governance_token';
+import GovernorMock from 'typechain/contracts/governor_mock';
+import { Proposal, Vote } from 'typechain/types-arguments/governor_mock';
+import { GovernErrorBuilder as GovernErrorArgumentBuilder } from 'typechain/types-arguments/governor_mock';
+import { GovernErrorBuilder, ProposalStatus, StakeErrorBuilder } from 'typechain/types-returns/governor_mock';
+import { TestEnv, makeSuite } from './utlis/make-suite';
+import { defaultGovernorConfig, deployGovernorMock } from './setup/deploymentHelpers';
+import { hexToNumbers } from './utlis/converters/hex-number';
+import { expect } from './setup/chai';
+import { replaceRNPropsWithStrings } from './utlis/misc';
+
+makeSuite('Governor mock tests', (getTestEnv) => {
+  let testEnv: TestEnv;
+  let deployer: KeyringPair;
+  let users: KeyringPair[];
+  let governor: GovernorMock;
+  let govToken: GovernanceToken;
+  let timestmpProvider: BlockTimestsampProvider;
+
+  const midStake = E6.mul(E12);
+  const smallStake = E6.mul(E12).divn(10);
+
+  beforeEach(async () => {
+    testEnv = getTestEnv();
+    deployer = testEnv.deployer;
+    users = testEnv.users;
+    govToken = testEnv.govToken;
+    timestmpProvider = testEnv.timestampProvider;
+
+    governor = await deployGovernorMock(
+      deployer,
+      govToken.address,
+      defaultGovernorConfig.minimalUnstakePeriod,
+      defaultGovernorConfig.unstake_period,
+      defaultGovernorConfig.maximalNumberOfUnstakes,
+      defaultGovernorConfig.rules,
+    );
+    await governor.withSigner(deployer).tx.setTimestampProvider(timestmpProvider.address);
+
+    await govToken.withSigner(deployer).tx.transfer(governor.address, midStake, []);
+    await govToken.withSigner(deployer).tx.transfer(users[0].address, smallStake, []);
+    await govToken.withSigner(users[0]).tx.increaseAllowance(governor.address, E21);
+  });
+
+  describe('stake made before checkpointing was introduced', () => {
+    beforeEach(async () => {
+      await governor.withSigner(deployer).tx.mockSetLegacyStake(users[0].address, midStake);
+    });
+
+    it('is returned by stake_at although no checkpoint was written', async () => {
+      const now = (await timestmpProvider.query.getBlockTimestamp()).value.ok!;
+      expect((await governor.query.stakeAt(users[0].address, now)).value.ok!.rawNumber.toString()).to.be.equal(midStake.toString());
+      expect((await governor.query.stakeAt(users[0].address, 0)).value.ok!.rawNumber.toString()).to.be.equal(midStake.toString());
+    });
+
+    it('is kept in the history after the stake changes', async () => {
+      const before = (await timestmpProvider.query.getBlockTimestamp()).value.ok!;
+      await timestmpProvider.tx.increaseBlockTimestamp(1);
+      await governor.withSigner(users[0]).tx.stake(smallStake);
+      const after = (await timestmpProvider.query.getBlockTimestamp()).value.ok!;
+
+      expect((await governor.query.stakeAt(users[0].address, 0)).value.ok!.rawNumber.toString()).to.be.equal(midStake.toString());
+      expect((await governor.query.stakeAt(users[0].address, before)).value.ok!.rawNumber.toString()).to.be.equal(midStake.toString());
+      expect((await governor.query.stakeAt(users[0].address, after)).value.ok!.rawNumber.toString()).to.be.equal(
+        midStake.add(smallStake).toString(),
+      );
+    });
+
+    it('is delegated and later changes of it move the delegated power by the change only', async () => {
+      await governor.withSigner(users[0]).tx.delegate(users[1].address);
+      expect((await governor.query.delegatedPowerOf(users[1].address)).value.ok!.rawNumber.toString()).to.be.equal(midStake.toString());
+
+      await governor.withSigner(users[0]).tx.stake(smallStake);
+      expect((await governor.query.delegatedPowerOf(users[1].address)).value.ok!.rawNumber.toString()).to.be.equal(
+        midStake.add(smallStake).toString(),
+      );
+
+      await governor.withSigner(users[0]).tx.initializeUnstake(midStake);
+      expect((await governor.query.delegatedPowerOf(users[1].address)).value.ok!.rawNumber.toString()).to.be.equal(smallStake.toString());
+    });
+  });
+
+  describe('unstakes queue starting just before the maximal unstake id', () => {
+    let initTimes: number[];
+    beforeEach(async () => {
+      await governor.withSigner(users[0]).tx.stake(smallStake);
+      await governor.withSigner(deployer).tx.mockSetUnstakesQueueStart(users[0].address, 2 ** 32 - 2);
+      initTimes = [];
+      for (let i = 0; i < 3; i++) {
+        initTimes.push((await timestmpProvider.query.getBlockTimestamp()).value.ok!);
+        await governor.withSigner(users[0]).tx.initializeUnstake(smallStake.divn(4));
+        await timestmpProvider.tx.increaseBlockTimestamp(1);
+      }
+    });
+
+    it('keeps the order of unstakes wrapping around the maximal id', async () => {
+      expect(replaceRNPropsWithStrings((await governor.query.initializedUnstakesOf(users[0].address)).value.ok!)).to.deep.equal(
+        initTimes.map((initTime) => ({ initTime, amount: smallStake.divn(4).toString() })),
+      );
+      const queryRes = (await governor.withSigner(users[0]).query.initializeUnstake(smallStake.divn(4))).value.ok;
+      expect(queryRes).to.have.deep.property('err', StakeErrorBuilder.ToManyUnstakes());
+    });
+
+    it('cancels the unstake at the maximal id and unstakes the remaining ones', async () => {
+      await governor.withSigner(users[0]).tx.cancelUnstake(1);
+      expect(replaceRNPropsWithStrings((await governor.query.initializedUnstakesOf(users[0].address)).value.ok!)).to.deep.equal(
+        [initTimes[0], initTimes[2]].map((initTime) => ({ initTime, amount: smallStake.divn(4).toString() })),
+      );
+
+      await timestmpProvider.tx.increaseBlockTimestamp(21 * DAY);
+      await governor.withSigner(users[0]).tx.unstake();
+      expect((await govToken.query.balanceOf(users[0].address)).value.ok!.rawNumber.toString()).to.be.equal(smallStake.divn(2).toString());
+      expect((await governor.query.initializedUnstakesOf(users[0].address)).value.ok!).to.deep.equal([]);
+      expect((await governor.query.totalUnstake()).value.ok!.rawNumber.toString()).to.be.equal('0');
+    });
+  });
+
+  describe('unstakes initialized before the unstakes queue was introduced', () => {
+    let legacyUnstakes: { initTime: number; amount: string }[];
+    beforeEach(async () => {
+      await governor.withSigner(users[0]).tx.stake(smallStake);
+      const now = (await timestmpProvider.query.getBlockTimestamp()).value.ok!;
+      legacyUnstakes = [
+        { initTime: now, amount: smallStake.divn(4).toString() },
+        { initTime: now + DAY, amount: smallStake.divn(4).toString() },
+      ];
+      await governor.withSigner(deployer).tx.mockSetLegacyUnstakes(users[0].address, legacyUnstakes);
+    });
+
+    it('are returned by initialized_unstakes_of', async () => {
+      expect(replaceRNPropsWithStrings((await governor.query.initializedUnstakesOf(users[0].address)).value.ok!)).to.deep.equal(legacyUnstakes);
+      expect((await governor.query.totalUnstake()).value.ok!.rawNumber.toString()).to.be.equal(smallStake.divn(2).toString());
+    });
+
+    it('are kept before a new unstake and count towards the maximal number of unstakes', async () => {
+      await timestmpProvider.tx.increaseBlockTimestamp(2 * DAY);
+      const initTime = (await timestmpProvider.query.getBlockTimestamp()).value.ok!;
+      await governor.withSigner(users[0]).tx.initializeUnstake(smallStake.divn(4));
+      expect(replaceRNPropsWithStrings((await governor.query.initializedUnstakesOf(users[0].address)).value.ok!)).to.deep.equal([
+        ...legacyUnstakes,
+        { initTime, amount: smallStake.divn(4).toString() },
+      ]);
+
+      const queryRes = (await governor.withSigner(users[0]).query.initializeUnstake(smallStake.divn(4))).value.ok;
+      expect(queryRes).to.have.deep.property('err', StakeErrorBuilder.ToManyUnstakes());
+    });
+
+    it('are cancelled', async () => {
+      await governor.withSigner(users[0]).tx.cancelUnstake(0);
+      expect(replaceRNPropsWithStrings((await governor.query.initializedUnstakesOf(users[0].address)).value.ok!)).to.deep.equal([legacyUnstakes[1]]);
+      expect((await governor.query.stakeOf(users[0].address)).value.ok!.rawNumber.toString()).to.be.equal(
+        smallStake.add(smallStake.divn(4)).toString(),
+      );
+    });
+
+    it('are unstaked', async () => {
+      await timestmpProvider.tx.increaseBlockTimestamp(22 * DAY);
+      await governor.withSigner(users[0]).tx.unstake();
+      expect((await govToken.query.balanceOf(users[0].address)).value.ok!.rawNumber.toString()).to.be.equal(smallStake.divn(2).toString());
+      expect((await governor.query.initializedUnstakesOf(users[0].address)).value.ok!).to.deep.equal([]);
+      expect((await governor.query.totalUnstake()).value.ok!.rawNumber.toString()).to.be.equal('0');
+    });
+  });
+
+  describe('hooks returning an error', () => {
+    const description = 'Abax will be the best ;-)';
+    const proposal: Proposal = { rulesId: 0, voterRewardPartE12: 0, transactions: [] };
+    let proposalId: number[];
+    beforeEach(async () => {
+      await governor.withSigner(deployer).tx.mockSetLegacyStake(users[0].address, midStake);
+      proposalId = hexToNumbers(((await testEnv.hasher.query.hashProposalWithDescription(proposal, description)).value.ok! as string).substring(2));
+    });
+
+    it('make propose fail with the error of _before_propose', async () => {
+      await governor.withSigner(deployer).tx.mockSetHooksError(GovernErrorArgumentBuilder.WrongParameters());
+      const queryRes = (await governor.withSigner(users[0]).query.propose(proposal, description)).value.ok;
+      expect(queryRes).to.have.deep.property('err', GovernErrorBuilder.WrongParameters());
+
+      await governor.withSigner(deployer).tx.mockSetHooksError(null);
+      await expect(governor.withSigner(users[0]).tx.propose(proposal, description)).to.eventually.be.fulfilled;
+    });
+
+    it('make execute fail with the error of _before_execute', async () => {
+      await governor.withSigner(users[0]).tx.propose(proposal, description);
+      await governor.withSigner(users[0]).tx.vote(proposalId, Vote.agreed, []);
+      await governor.tx.finalize(proposalId);
+      await governor.tx.queue(proposalId);
+      await timestmpProvider.tx.increaseBlockTimestamp(2 * DAY);
+
+      await governor.withSigner(deployer).tx.mockSetHooksError(GovernErrorArgumentBuilder.WrongParameters());
+      const queryRes = (await governor.withSigner(users[0]).query.executeById(proposalId)).value.ok;
+      expect(queryRes).to.have.deep.property('err', GovernErrorBuilder.WrongParameters());
+      expect((await governor.query.status(proposalId)).value.ok).to.be.equal(ProposalStatus.queued);
+
+      await governor.withSigner(deployer).tx.mockSetHooksError(null);
+      await expect(governor.withSigner(users[0]).tx.executeById(proposalId)).to.eventually.be.fulfilled;
+      expect((await governor.query.status(proposalId)).value.ok).to.be.equal(ProposalStatus.executed);
+    });
+  });
+});
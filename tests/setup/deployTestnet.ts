@@ -6,6 +6,7 @@ import { argvObj } from 'scripts/compile/common';
 import Keyring from '@polkadot/keyring';
 import { ReturnNumber } from '@727-ventures/typechain-types';
 import { DAY } from 'scripts/types_and_consts';
+import { FinalizationCurveBuilder } from 'typechain/types-returns/governor';
 
 (async (args: Record<string, string>) => {
   if (require.main !== module) return;
@@ -36,9 +37,18 @@ import { DAY } from 'scripts/types_and_consts';
           initialPeriod: 1 * DAY, //3 * DAY,
           flatPeriod: 2 * DAY, //7 * DAY,
           finalPeriod: 1 * DAY, //4 * DAY,
+          finalizationCurve: FinalizationCurveBuilder.Linear3Phase(),
           maximalVoterRewardPartE12: 0, //toE12(0.05),
           voterSlashPartE12: 0, //toE12(0.2),
+          voterSlashGracePeriod: 0, //1 * DAY,
+          slashBountyPartE12: 0, //toE12(0.1),
           proposerSlashPartE12: 0, //toE12(0.5),
+          quorumPartE12: 0, //toE12(0.1),
+          ownerCancelReturnsDeposit: true,
+          executionDelay: 0, //2 * DAY,
+          executionGracePeriod: 7 * DAY,
+          maximalVoteReasonLength: 256,
+          storeVoteReasons: true,
         },
       },
     },
@@ -14,13 +14,15 @@ import { LINE_SEPARATOR, toE12 } from 'tests/utlis/misc';
 import { TestEnv } from 'tests/utlis/make-suite';
 import PSP22Mintable from 'typechain/contracts/psp22_mintable';
 import Governor from 'typechain/contracts/governor';
+import GovernorMock from 'typechain/contracts/governor_mock';
 import Staker from 'typechain/contracts/staker';
 import GovernanceToken from 'typechain/contracts/governance_token';
 import Hasher from 'typechain/contracts/hasher';
 import BlockTimestampProvider from 'typechain/contracts/block_timestamp_provider';
 import GovernanceTokenMinter from 'typechain/contracts/governance_token_minter';
+import Treasury from 'typechain/contracts/treasury';
 import { AccountId, BURNER, Balance, DAY, E21, MINTER, Option, Timestamp } from 'scripts/types_and_consts';
-import { ProposalRules } from 'typechain/types-returns/governor';
+import { FinalizationCurveBuilder, ProposalRules } from 'typechain/types-returns/governor';
 import { ReturnNumber } from '@727-ventures/typechain-types';
 import { merge } from 'lodash';
 
@@ -108,10 +110,20 @@ export const deployPSP22Mintable = async (owner: KeyringPair, initialSupply: Bal
 export const deployGovernor = async (
   owner: KeyringPair,
   want: string,
+  minimalUnstakePeriod: BN | number | string,
   unstakePeriod: BN | number | string,
   maximalNumberOfUnstakes: BN | number | string,
   rules: ProposalRules,
-) => await deployWithLog(owner, Governor, 'governor', want, unstakePeriod, maximalNumberOfUnstakes, rules);
+) => await deployWithLog(owner, Governor, 'governor', want, minimalUnstakePeriod, unstakePeriod, maximalNumberOfUnstakes, rules);
+
+export const deployGovernorMock = async (
+  owner: KeyringPair,
+  want: string,
+  minimalUnstakePeriod: BN | number | string,
+  unstakePeriod: BN | number | string,
+  maximalNumberOfUnstakes: BN | number | string,
+  rules: ProposalRules,
+) => await deployWithLog(owner, GovernorMock, 'governor_mock', want, minimalUnstakePeriod, unstakePeriod, maximalNumberOfUnstakes, rules);
 
 export const deployGovernanceToken = async (
   owner: KeyringPair,
@@ -124,9 +136,10 @@ export const deployGovernanceToken = async (
 export const deployStaker = async (
   owner: KeyringPair,
   want: string,
+  minimalUnstakePeriod: BN | number | string,
   unstakePeriod: BN | number | string,
   maximal_number_of_initialized_unstakes: number,
-) => await deployWithLog(owner, Staker, 'staker', want, unstakePeriod, maximal_number_of_initialized_unstakes);
+) => await deployWithLog(owner, Staker, 'staker', want, minimalUnstakePeriod, unstakePeriod, maximal_number_of_initialized_unstakes);
 
 export const deployBlockTimestampProvider = async (owner: KeyringPair, shouldReturnMockValue = false) =>
   await deployWithLog(owner, BlockTimestampProvider, 'block_timestamp_provider', shouldReturnMockValue, owner.address);
@@ -135,6 +148,8 @@ export const deployGovernanceTokenMinter = async (owner: KeyringPair, govTokenAd
   return deployWithLog(owner, GovernanceTokenMinter, 'governance_token_minter', govTokenAddress);
 };
 
+export const deployTreasury = async (owner: KeyringPair, governorAddress: string) => await deployWithLog(owner, Treasury, 'treasury', governorAddress);
+
 // const getSelectorsFromMessages = (messages) => {
 //   return messages.map((message) => {
 //     return message.selector;
@@ -239,6 +254,7 @@ export async function deployCoreContracts(config: DeploymentConfig): Promise<{
     governor = await deployGovernor(
       config.deployer,
       govToken.address,
+      governorConfig.minimalUnstakePeriod,
       governorConfig.unstake_period,
       governorConfig.maximalNumberOfUnstakes,
       governorConfig.rules,
@@ -250,6 +266,7 @@ export async function deployCoreContracts(config: DeploymentConfig): Promise<{
   const staker: Staker = await deployStaker(
     config.deployer,
     psp22Mintable.address,
+    stakerConfig.minimal_unstake_period,
     stakerConfig.unstake_period,
     stakerConfig.maximal_number_of_initialized_unstakes,
   );
@@ -274,6 +291,7 @@ const getEntryOrThrow = <T>(record: Record<string, T>, key: string) => {
 
 export type GovernorConfig = {
   want: Option<AccountId>;
+  minimalUnstakePeriod: Timestamp;
   unstake_period: Timestamp;
   maximalNumberOfUnstakes: number;
   rules: ProposalRules;
@@ -281,6 +299,7 @@ export type GovernorConfig = {
 
 export type StakerConfig = {
   want: Option<AccountId>;
+  minimal_unstake_period: Timestamp;
   unstake_period: Timestamp;
   maximal_number_of_initialized_unstakes: number;
 };
@@ -291,13 +310,23 @@ export const defaultProposalRules: ProposalRules = {
   initialPeriod: 3 * DAY,
   flatPeriod: 7 * DAY,
   finalPeriod: 4 * DAY,
+  finalizationCurve: FinalizationCurveBuilder.Linear3Phase(),
   maximalVoterRewardPartE12: toE12(0.05),
   voterSlashPartE12: toE12(0.2),
+  voterSlashGracePeriod: DAY,
+  slashBountyPartE12: toE12(0.1),
   proposerSlashPartE12: toE12(0.5),
+  quorumPartE12: toE12(0.1),
+  ownerCancelReturnsDeposit: true,
+  executionDelay: 2 * DAY,
+  executionGracePeriod: 7 * DAY,
+  maximalVoteReasonLength: 256,
+  storeVoteReasons: true,
 };
 
 export const defaultGovernorConfig = {
   want: null,
+  minimalUnstakePeriod: 7 * DAY,
   unstake_period: 21 * DAY,
   maximalNumberOfUnstakes: 3,
   rules: defaultProposalRules,
@@ -305,6 +334,7 @@ export const defaultGovernorConfig = {
 
 export const defaultStakerConfig = {
   want: null,
+  minimal_unstake_period: 7 * DAY,
   unstake_period: 21 * DAY,
   maximal_number_of_initialized_unstakes: 3,
 };
@@ -363,6 +393,9 @@ export const deployAndConfigureSystem = async (
   await governor.tx.setTimestampProvider(timestampProvider.address);
   await staker.tx.setTimestampProvider(timestampProvider.address);
 
+  const treasury = await deployTreasury(deployer, governor.address);
+  await treasury.tx.setTimestampProvider(timestampProvider.address);
+
   //FOR ETHWARSAW TESTING PURPOSES
   const governanceTokenMinter = await deployGovernanceTokenMinter(deployer, govToken.address);
   // await govToken.withSigner(deployer).tx.grantRole(MINTER, governanceTokenMinter.address);
@@ -384,6 +417,7 @@ export const deployAndConfigureSystem = async (
     psp22Mintable,
     hasher,
     governanceTokenMinter,
+    treasury,
   };
 
   if (saveConfigToFilePath) {
@@ -423,6 +457,10 @@ async function saveConfigToFile(testEnv: TestEnv, writePath: string) {
         name: testEnv.governanceTokenMinter.name,
         address: testEnv.governanceTokenMinter.address,
       },
+      {
+        name: testEnv.treasury.name,
+        address: testEnv.treasury.address,
+      },
     ],
     writePath,
   );
@@ -12,6 +12,7 @@ import Hasher from 'typechain/contracts/hasher';
 import Governor from 'typechain/contracts/governor';
 import GovernanceToken from 'typechain/contracts/governance_token';
 import GovernanceTokenMinter from 'typechain/contracts/governance_token_minter';
+import Treasury from 'typechain/contracts/treasury';
 import BlockTimestampProvider from 'typechain/contracts/block_timestamp_provider';
 import { TestEnv } from 'tests/utlis/make-suite';
 
@@ -127,6 +128,9 @@ export const readContractsFromFile = async (writePath = DEFAULT_DEPLOYED_CONTRAC
   const govTokenMinterInfo = contracts.find((c) => c.name === 'governance_token_minter');
   if (!govTokenMinterInfo) throw 'hasher ContractInfo not found';
   const governanceTokenMinter = await getContractObject(GovernanceTokenMinter, govTokenMinterInfo.address, deployer);
+  const treasuryContractInfo = contracts.find((c) => c.name === 'treasury');
+  if (!treasuryContractInfo) throw 'treasury ContractInfo not found';
+  const treasury = await getContractObject(Treasury, treasuryContractInfo.address, deployer);
 
   return {
     users,
@@ -138,6 +142,7 @@ export const readContractsFromFile = async (writePath = DEFAULT_DEPLOYED_CONTRAC
     psp22Mintable,
     hasher,
     governanceTokenMinter,
+    treasury,
   };
 };
 
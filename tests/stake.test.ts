@@ -8,11 +8,12 @@ import { TestEnv, makeSuite } from './utlis/make-suite';
 import { DAY, E12, E21, E6 } from 'scripts/types_and_consts';
 // import { GovernErrorBuilder, PSP22ErrorBuilder, StakeErrorBuilder } from 'typechain/types-returns/governor';
 import { stakeAndCheck } from './utlis/checkers/staker/stake';
-import { PSP22ErrorBuilder, StakeErrorBuilder } from 'typechain/types-returns/staker';
+import { AccessControlErrorBuilder, PSP22ErrorBuilder, StakeErrorBuilder } from 'typechain/types-returns/staker';
 import { initializeUnstakeAndCheck } from './utlis/checkers/staker/initializeUnstake';
 import { unstakeAndCheck } from './utlis/checkers/staker/unstake';
 import { rewardAndCheck } from './utlis/checkers/staker/reward';
 import { slashAndCheck } from './utlis/checkers/staker/slash';
+import { cancelUnstakeAndCheck } from './utlis/checkers/staker/cancelUnstake';
 import { expect } from './setup/chai';
 import { replaceRNPropsWithStrings } from './utlis/misc';
 
@@ -219,6 +220,99 @@ makeSuite('Stake tests', (getTestEnv) => {
             await unstakeAndCheck(testEnv, users[0], undefined);
             await unstakeAndCheck(testEnv, users[0], StakeErrorBuilder.NoInitializedUnstakes());
           });
+          it(`tries to unstake 0 to user1`, async () => {
+            const queryRes = (await staker.withSigner(users[0]).query.unstakeTo(users[1].address, 0)).value.ok;
+            expect(queryRes).to.have.deep.property('err', StakeErrorBuilder.AmountIsZero());
+          });
+          it(`unstakes 3/4 of his unstakes to user1 - first unstake is released and second is split`, async () => {
+            const [firstUnstake, secondUnstake] = (await staker.query.initializedUnstakesOf(users[0].address)).value.ok!;
+            const maxAmount = amountStaked.muln(3).divn(4);
+            const tx = staker.withSigner(users[0]).tx.unstakeTo(users[1].address, maxAmount);
+            await expect(tx).to.eventually.be.fulfilled;
+            const txRes = await tx;
+
+            expect.soft((await psp22Mintable.query.balanceOf(users[1].address)).value.ok!.rawNumber.toString()).to.be.equal(maxAmount.toString());
+            expect.soft(replaceRNPropsWithStrings((await staker.query.initializedUnstakesOf(users[0].address)).value.ok!)).to.deep.equal([
+              { initTime: secondUnstake.initTime, amount: amountStaked.divn(4).toString() },
+            ]);
+            expect.soft((await staker.query.totalUnstake()).value.ok!.rawNumber.toString()).to.be.equal(amountStaked.divn(4).toString());
+            expect.soft(replaceRNPropsWithStrings(txRes.events)).to.deep.equal([
+              {
+                name: 'Unstaked',
+                args: {
+                  caller: users[0].address,
+                  recipient: users[1].address,
+                  amount: maxAmount.toString(),
+                  unstakes: [
+                    { initTime: firstUnstake.initTime, amount: amountStaked.divn(2).toString() },
+                    { initTime: secondUnstake.initTime, amount: amountStaked.divn(4).toString() },
+                  ],
+                  remainingUnstakes: 1,
+                },
+              },
+            ]);
+            expect.flushSoft();
+
+            await unstakeAndCheck(testEnv, users[0], undefined);
+            await unstakeAndCheck(testEnv, users[0], StakeErrorBuilder.NoInitializedUnstakes());
+          });
+          it(`unstakes 3/4 of his unstakes to user1 and cancels the remaining part of the split unstake`, async () => {
+            await staker.withSigner(users[0]).tx.unstakeTo(users[1].address, amountStaked.muln(3).divn(4));
+
+            await cancelUnstakeAndCheck(testEnv, users[0], 1, StakeErrorBuilder.UnstakeNotInitialized());
+            await cancelUnstakeAndCheck(testEnv, users[0], 0, undefined);
+            expect((await staker.query.stakeOf(users[0].address)).value.ok!.rawNumber.toString()).to.be.equal(amountStaked.divn(4).toString());
+            expect((await staker.query.totalUnstake()).value.ok!.rawNumber.toString()).to.be.equal('0');
+            await unstakeAndCheck(testEnv, users[0], StakeErrorBuilder.NoInitializedUnstakes());
+          });
+        });
+      });
+    });
+  });
+
+  describe(`Cancel Unstake : user0 `, () => {
+    it(`tries to cancel unstake but hasn't done any action before`, async () => {
+      await cancelUnstakeAndCheck(testEnv, users[0], 0, StakeErrorBuilder.NoInitializedUnstakes());
+    });
+    it(`tries to restake all unstakes but hasn't done any action before`, async () => {
+      await cancelUnstakeAndCheck(testEnv, users[0], undefined, StakeErrorBuilder.NoInitializedUnstakes());
+    });
+    describe(`stakes E6*E12 tokens and initializes unstake for 1/4 of stake twice, now and 1 DAY later, and then...`, () => {
+      const amountStaked = E6.mul(E12).divn(2);
+      beforeEach(async () => {
+        await psp22Mintable.withSigner(users[0]).tx.increaseAllowance(staker.address, E21);
+        await psp22Mintable.withSigner(deployer).tx.transfer(users[0].address, E6.mul(E12), []);
+        await staker.withSigner(users[0]).tx.stake(amountStaked);
+        await staker.withSigner(users[0]).tx.initializeUnstake(amountStaked.divn(4));
+        await timestmpProvider.tx.increaseBlockTimestamp(1 * DAY);
+        await staker.withSigner(users[0]).tx.initializeUnstake(amountStaked.divn(4));
+      });
+      it(`tries to cancel unstake that doesn't exist`, async () => {
+        await cancelUnstakeAndCheck(testEnv, users[0], 2, StakeErrorBuilder.UnstakeNotInitialized());
+      });
+      it(`cancels the first unstake successfully - event is emitted, state of the contract is updated`, async () => {
+        await cancelUnstakeAndCheck(testEnv, users[0], 0, undefined);
+      });
+      it(`cancels both unstakes one by one successfully - event is emitted, state of the contract is updated`, async () => {
+        await cancelUnstakeAndCheck(testEnv, users[0], 1, undefined);
+        await cancelUnstakeAndCheck(testEnv, users[0], 0, undefined);
+        await cancelUnstakeAndCheck(testEnv, users[0], 0, StakeErrorBuilder.NoInitializedUnstakes());
+      });
+      it(`restakes all unstakes successfully - event is emitted, state of the contract is updated`, async () => {
+        await cancelUnstakeAndCheck(testEnv, users[0], undefined, undefined);
+        expect((await staker.query.stakeOf(users[0].address)).value.ok!.rawNumber.toString()).to.be.equal(amountStaked.toString());
+        expect((await staker.query.lastStakeTimestampOf(users[0].address)).value.ok).to.be.equal(
+          (await timestmpProvider.query.getBlockTimestamp()).value.ok!,
+        );
+      });
+      describe(`21 Days later`, () => {
+        beforeEach(async () => {
+          await timestmpProvider.tx.increaseBlockTimestamp(21 * DAY);
+        });
+        it(`cancels the second unstake and unstakes only the first one`, async () => {
+          await cancelUnstakeAndCheck(testEnv, users[0], 1, undefined);
+          await unstakeAndCheck(testEnv, users[0], undefined);
+          await unstakeAndCheck(testEnv, users[0], StakeErrorBuilder.NoInitializedUnstakes());
         });
       });
     });
@@ -277,10 +371,31 @@ makeSuite('Stake tests', (getTestEnv) => {
         it(`someone slashes user, by more then his stake, event is emitted, storage is updated`, async () => {
           await slashAndCheck(testEnv, users[0], users[0], amountStaked.muln(2), amountStaked, undefined);
         });
+        it(`someone slashes user, by exactly his stake and unstakes, which empties the unstakes queue that can be used again`, async () => {
+          await slashAndCheck(testEnv, users[0], users[0], amountStaked, amountStaked, undefined);
+          expect((await staker.query.initializedUnstakesOf(users[0].address)).value.ok!).to.deep.equal([]);
+          await unstakeAndCheck(testEnv, users[0], StakeErrorBuilder.NoInitializedUnstakes());
+          await cancelUnstakeAndCheck(testEnv, users[0], 0, StakeErrorBuilder.NoInitializedUnstakes());
+
+          await staker.withSigner(users[0]).tx.stake(amountStaked);
+          for (let i = 0; i < 3; i++) {
+            await initializeUnstakeAndCheck(testEnv, users[0], amountStaked.divn(4), undefined);
+          }
+          await initializeUnstakeAndCheck(testEnv, users[0], amountStaked.divn(4), StakeErrorBuilder.ToManyUnstakes());
+        });
       });
     });
   });
   describe('Change Unstake Period', () => {
+    it('user1 without STAKE_ADMIN role tries to change unstake period', async () => {
+      const queryRes = (await staker.withSigner(users[1]).query.changeUnstakePeriod(10 * DAY)).value.ok;
+      expect(queryRes).to.have.deep.property('err', StakeErrorBuilder.AccessControlError(AccessControlErrorBuilder.MissingRole()));
+    });
+    it('deployer tries to change unstake period below the minimal unstake period', async () => {
+      const minimalUnstakePeriod = (await staker.query.minimalUnstakePeriod()).value.ok!;
+      const queryRes = (await staker.query.changeUnstakePeriod(minimalUnstakePeriod - 1)).value.ok;
+      expect(queryRes).to.have.deep.property('err', StakeErrorBuilder.WrongParameters());
+    });
     it('user0 changes unstake period', async () => {
       const tx = staker.tx.changeUnstakePeriod(10 * DAY);
       await expect(tx).to.be.eventually.fulfilled;
@@ -299,6 +414,18 @@ makeSuite('Stake tests', (getTestEnv) => {
   });
 
   describe('Change Maximal Number Of Unstakes', () => {
+    it('user1 without STAKE_ADMIN role tries to change maximal number of unstakes', async () => {
+      const queryRes = (await staker.withSigner(users[1]).query.changeMaximalNumberOfUnstakes(5)).value.ok;
+      expect(queryRes).to.have.deep.property('err', StakeErrorBuilder.AccessControlError(AccessControlErrorBuilder.MissingRole()));
+    });
+    it('deployer tries to change maximal number of unstakes to 0', async () => {
+      const queryRes = (await staker.query.changeMaximalNumberOfUnstakes(0)).value.ok;
+      expect(queryRes).to.have.deep.property('err', StakeErrorBuilder.WrongParameters());
+    });
+    it('deployer tries to change maximal number of unstakes above the limit', async () => {
+      const queryRes = (await staker.query.changeMaximalNumberOfUnstakes(257)).value.ok;
+      expect(queryRes).to.have.deep.property('err', StakeErrorBuilder.WrongParameters());
+    });
     it('user0 changes maximal number of unstakes', async () => {
       const tx = staker.tx.changeMaximalNumberOfUnstakes(5);
       await expect(tx).to.be.eventually.fulfilled;
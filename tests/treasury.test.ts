@@ -0,0 +1,139 @@
+import { KeyringPair } from '@polkadot/keyring/types';
+import { DAY, E12, E21, E6 } from 'scripts/types_and_consts';
+import BlockTimestsampProvider from 'typechain/contracts/block_timestamp_provider';
+import GovernanceToken from 'typechain/contracts/governance_token';
+import Governor from 'typechain/contracts/governor';
+import Treasury from 'typechain/contracts/treasury';
+import { Proposal, Transaction, Vote } from 'typechain/types-arguments/governor';
+import { AssetBuilder } from 'typechain/types-arguments/treasury';
+import { ProposalStatus } from 'typechain/types-returns/governor';
+import { OwnableErrorBuilder, TreasuryErrorBuilder } from 'typechain/types-returns/treasury';
+import { hexToNumbers, paramsToInputNumbers } from './utlis/converters/hex-number';
+import { TestEnv, makeSuite } from './utlis/make-suite';
+import { expect } from './setup/chai';
+
+makeSuite('Treasury tests', (getTestEnv) => {
+  let testEnv: TestEnv;
+  let deployer: KeyringPair;
+  let users: KeyringPair[];
+  let governor: Governor;
+  let govToken: GovernanceToken;
+  let treasury: Treasury;
+  let timestmpProvider: BlockTimestsampProvider;
+
+  const bigStake = E6.mul(E12).muln(10);
+  const treasuryFunds = E6.mul(E12);
+  const description = 'Abax treasury payout';
+
+  const treasuryTransaction = (message: string, args: unknown[]): Transaction => {
+    const params = paramsToInputNumbers(treasury.abi.findMessage(message).toU8a(args));
+    return {
+      callee: treasury.address,
+      selector: params.selector,
+      input: params.data,
+      transferredValue: 0,
+      gasLimit: null,
+      allowFailure: false,
+    };
+  };
+
+  const passAndExecute = async (transactions: Transaction[]) => {
+    const proposal: Proposal = {
+      rulesId: 0,
+      voterRewardPartE12: 0,
+      transactions,
+    };
+    const proposalId = hexToNumbers(((await testEnv.hasher.query.hashProposalWithDescription(proposal, description)).value.ok! as string).substring(2));
+    await governor.withSigner(users[0]).tx.propose(proposal, description);
+    await governor.withSigner(users[0]).tx.vote(proposalId, Vote.agreed, []);
+    await governor.withSigner(users[1]).tx.vote(proposalId, Vote.agreed, []);
+    await timestmpProvider.tx.increaseBlockTimestamp(9 * DAY);
+    await governor.tx.finalize(proposalId);
+    await governor.tx.queue(proposalId);
+    await timestmpProvider.tx.increaseBlockTimestamp(2 * DAY);
+    const tx = governor.withSigner(users[0]).tx.executeById(proposalId);
+    await expect(tx).to.eventually.be.fulfilled;
+    expect((await governor.query.status(proposalId)).value.ok).to.be.equal(ProposalStatus.executed);
+  };
+
+  beforeEach(async () => {
+    testEnv = getTestEnv();
+    deployer = testEnv.deployer;
+    users = testEnv.users;
+    governor = testEnv.governor;
+    govToken = testEnv.govToken;
+    treasury = testEnv.treasury;
+    timestmpProvider = testEnv.timestampProvider;
+
+    await govToken.withSigner(deployer).tx.transfer(users[0].address, bigStake, []);
+    await govToken.withSigner(deployer).tx.transfer(users[1].address, bigStake, []);
+    await govToken.withSigner(deployer).tx.transfer(treasury.address, treasuryFunds, []);
+
+    await govToken.withSigner(users[0]).tx.increaseAllowance(governor.address, E21);
+    await govToken.withSigner(users[1]).tx.increaseAllowance(governor.address, E21);
+
+    await governor.withSigner(users[0]).tx.stake(bigStake);
+    await governor.withSigner(users[1]).tx.stake(bigStake);
+    await timestmpProvider.tx.increaseBlockTimestamp(1);
+  });
+
+  it('treasury is owned by the governor', async () => {
+    expect((await treasury.query.owner()).value.ok).to.be.equal(governor.address);
+  });
+  it('user0 who is not the governor tries to transfer PSP22 from the treasury', async () => {
+    const queryRes = (await treasury.withSigner(users[0]).query.transferPsp22(govToken.address, users[0].address, 1)).value.ok;
+    expect(queryRes).to.have.deep.property('err', TreasuryErrorBuilder.OwnableError(OwnableErrorBuilder.CallerIsNotOwner()));
+  });
+  it('user0 who is not the governor tries to create a stream', async () => {
+    const queryRes = (await treasury.withSigner(users[0]).query.createStream(AssetBuilder.PSP22(govToken.address), users[0].address, 1, 0, DAY)).value
+      .ok;
+    expect(queryRes).to.have.deep.property('err', TreasuryErrorBuilder.OwnableError(OwnableErrorBuilder.CallerIsNotOwner()));
+  });
+  it('executed proposal transfers PSP22 from the treasury to user2', async () => {
+    await passAndExecute([treasuryTransaction('Treasury::transfer_psp22', [govToken.address, users[2].address, E12.toString()])]);
+    expect((await govToken.query.balanceOf(users[2].address)).value.ok!.rawNumber.toString()).to.be.equal(E12.toString());
+    expect((await govToken.query.balanceOf(treasury.address)).value.ok!.rawNumber.toString()).to.be.equal(treasuryFunds.sub(E12).toString());
+  });
+  it('executed proposal approves user2 to spend the treasury PSP22', async () => {
+    await passAndExecute([treasuryTransaction('Treasury::approve_psp22', [govToken.address, users[2].address, E12.toString()])]);
+    expect((await govToken.query.allowance(treasury.address, users[2].address)).value.ok!.rawNumber.toString()).to.be.equal(E12.toString());
+  });
+  describe('executed proposal creates the stream of PSP22 to user2 lasting 10 days', () => {
+    const streamDuration = 10 * DAY;
+    beforeEach(async () => {
+      const start = (await treasury.query.timestamp()).value.ok! + 11 * DAY;
+      await passAndExecute([
+        treasuryTransaction('Treasury::create_stream', [{ PSP22: govToken.address }, users[2].address, treasuryFunds.toString(), start, streamDuration]),
+      ]);
+    });
+    it('the streamed amount is reserved', async () => {
+      expect((await treasury.query.reservedBalance(AssetBuilder.PSP22(govToken.address))).value.ok!.rawNumber.toString()).to.be.equal(
+        treasuryFunds.toString(),
+      );
+      expect((await treasury.query.availableBalance(AssetBuilder.PSP22(govToken.address))).value.ok!.rawNumber.toString()).to.be.equal('0');
+    });
+    it('user3 tries to withdraw from the stream of user2', async () => {
+      const queryRes = (await treasury.withSigner(users[3]).query.withdrawFromStream(0)).value.ok;
+      expect(queryRes).to.have.deep.property('err', TreasuryErrorBuilder.CallerIsNotRecipient());
+    });
+    it('executed proposal fails to approve user3 to spend the reserved PSP22', async () => {
+      await passAndExecute([
+        { ...treasuryTransaction('Treasury::approve_psp22', [govToken.address, users[3].address, E12.toString()]), allowFailure: true },
+      ]);
+      expect((await govToken.query.allowance(treasury.address, users[3].address)).value.ok!.rawNumber.toString()).to.be.equal('0');
+    });
+    it('user2 withdraws half of the stream after 5 days and the rest after the stream ends', async () => {
+      await timestmpProvider.tx.increaseBlockTimestamp(streamDuration / 2);
+      await treasury.withSigner(users[2]).tx.withdrawFromStream(0);
+      expect((await govToken.query.balanceOf(users[2].address)).value.ok!.rawNumber.toString()).to.be.equal(treasuryFunds.divn(2).toString());
+
+      await timestmpProvider.tx.increaseBlockTimestamp(streamDuration);
+      await treasury.withSigner(users[2]).tx.withdrawFromStream(0);
+      expect((await govToken.query.balanceOf(users[2].address)).value.ok!.rawNumber.toString()).to.be.equal(treasuryFunds.toString());
+      expect((await treasury.query.reservedBalance(AssetBuilder.PSP22(govToken.address))).value.ok!.rawNumber.toString()).to.be.equal('0');
+
+      const queryRes = (await treasury.withSigner(users[2]).query.withdrawFromStream(0)).value.ok;
+      expect(queryRes).to.have.deep.property('err', TreasuryErrorBuilder.NothingToWithdraw());
+    });
+  });
+});
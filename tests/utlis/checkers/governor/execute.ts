@@ -37,6 +37,7 @@ export async function executeAndCheck(
   descriptionHash: number[],
   description: string,
   error?: GovernError | undefined,
+  outcomes?: any[],
 ) {
   const governor: Governor = testEnv.governor;
   const queryRes = (await governor.withSigner(caller).query.execute(proposal, descriptionHash)).value.ok;
@@ -64,6 +65,7 @@ export async function executeAndCheck(
       name: 'ProposalExecuted',
       args: {
         proposalId: '0x' + numbersToHex(proposalId as number[]),
+        outcomes: outcomes ?? proposal.transactions.map(() => ({ succeeded: '0x00' })),
       },
     },
   ]);
@@ -109,13 +109,16 @@ export async function finalizeAndCheck(
       proposer: storageStateBefore.state.proposer,
       voterRewardPartE12: storageStateBefore.state.voterRewardPartE12,
       rulesId: storageStateBefore.state.rulesId,
+      rulesVersion: storageStateBefore.state.rulesVersion,
       start: storageStateBefore.state.start,
       votesAtStart: storageStateBefore.state.votesAtStart,
       counterAtStart: storageStateBefore.state.counterAtStart,
       finalized: timestamp,
+      eta: storageStateBefore.state.eta,
       votesFor: storageStateBefore.state.votesFor,
       votesAgainst: storageStateBefore.state.votesAgainst,
       votesAgainstWithSlash: storageStateBefore.state.votesAgainstWithSlash,
+      votesAbstain: storageStateBefore.state.votesAbstain,
     },
     //[StakeStorage]
     proposer_stake:
@@ -61,6 +61,7 @@ export async function proposeAndCheck(
 
   const counterAtStart = (await governor.query.counterStake()).value.ok!;
   const totalStake = (await governor.query.totalStake()).value.ok!;
+  const rulesVersion = (await governor.query.rulesHistory(proposal.rulesId)).value.ok!.length - 1;
 
   const storageStateExpected: ProposeStorageModifications = {
     //[GovernorStorage]
@@ -69,13 +70,16 @@ export async function proposeAndCheck(
       proposer: caller.address,
       voterRewardPartE12: Number(proposal.voterRewardPartE12.toString()),
       rulesId: Number(proposal.rulesId.toString()),
+      rulesVersion: rulesVersion,
       start: timestamp,
       votesAtStart: totalStake,
       counterAtStart: counterAtStart,
       finalized: null,
+      eta: null,
       votesFor: new ReturnNumber(0),
       votesAgainst: new ReturnNumber(0),
       votesAgainstWithSlash: new ReturnNumber(0),
+      votesAbstain: new ReturnNumber(0),
     },
   };
 
@@ -92,6 +96,8 @@ export async function proposeAndCheck(
       selector: ('0x' + numbersToHex(proposal.transactions[i].selector as number[])) as any,
       input: ('0x' + numbersToHex(proposal.transactions[i].input as number[])) as any,
       transferredValue: proposal.transactions[i].transferredValue.toString(),
+      gasLimit: proposal.transactions[i].gasLimit,
+      allowFailure: proposal.transactions[i].allowFailure,
     });
   }
   expect.soft(replaceRNPropsWithStrings(txRes.events)).to.deep.equal([
@@ -30,14 +30,21 @@ export async function getVoteStorageModifications(governor: Governor, account: A
   /* eslint-enable @typescript-eslint/no-non-null-assertion */
 }
 
-export async function voteAndCheck(testEnv: TestEnv, caller: KeyringPair, proposalId: Hash, vote: Vote, error?: GovernError | undefined) {
+export async function voteAndCheck(
+  testEnv: TestEnv,
+  caller: KeyringPair,
+  proposalId: Hash,
+  vote: Vote,
+  error?: GovernError | undefined,
+  reason: number[] = [],
+) {
   const governor: Governor = testEnv.governor;
   const timestampProvider: BlockTimestampProvider = testEnv.timestampProvider;
 
   const storageStateBefore: VoteStorageModifications = await getVoteStorageModifications(governor, caller.address, proposalId);
   const timestamp = (await timestampProvider.query.getBlockTimestamp()).value.ok!; // eslint-disable-line @typescript-eslint/no-non-null-assertion
 
-  const queryRes = (await governor.withSigner(caller).query.vote(proposalId, vote, [])).value.ok;
+  const queryRes = (await governor.withSigner(caller).query.vote(proposalId, vote, reason)).value.ok;
   if (error !== undefined) {
     expect(queryRes).to.have.deep.property('err', error);
     return;
@@ -46,7 +53,7 @@ export async function voteAndCheck(testEnv: TestEnv, caller: KeyringPair, propos
   // const api = await apiProviderWrapper.getAndWaitForReady();
   // const governor2 = new ContractPromise(api, governor.abi, governor.address);
 
-  const tx = governor.withSigner(caller).tx.vote(proposalId, vote, []);
+  const tx = governor.withSigner(caller).tx.vote(proposalId, vote, reason);
   await expect(tx).to.eventually.be.fulfilled;
   const txRes = await tx;
 
@@ -64,10 +71,12 @@ export async function voteAndCheck(testEnv: TestEnv, caller: KeyringPair, propos
       proposer: storageStateBefore.state.proposer,
       voterRewardPartE12: storageStateBefore.state.voterRewardPartE12,
       rulesId: storageStateBefore.state.rulesId,
+      rulesVersion: storageStateBefore.state.rulesVersion,
       start: storageStateBefore.state.start,
       votesAtStart: storageStateBefore.state.votesAtStart,
       counterAtStart: storageStateBefore.state.counterAtStart,
       finalized: storageStateBefore.state.finalized,
+      eta: storageStateBefore.state.eta,
       votesFor: new ReturnNumber(
         storageStateBefore.state.votesFor.rawNumber
           .add(vote === Vote.agreed ? stakeOf.rawNumber : new BN(0))
@@ -95,6 +104,15 @@ export async function voteAndCheck(testEnv: TestEnv, caller: KeyringPair, propos
               : new BN(0),
           ),
       ),
+      votesAbstain: new ReturnNumber(
+        storageStateBefore.state.votesAbstain.rawNumber
+          .add(vote === Vote.abstained ? stakeOf.rawNumber : new BN(0))
+          .sub(
+            storageStateBefore.vote_of !== null && storageStateBefore.vote_of.vote === Vote.abstained
+              ? storageStateBefore.vote_of.amount.rawNumber
+              : new BN(0),
+          ),
+      ),
     },
     vote_of: {
       vote: vote,
@@ -111,6 +129,7 @@ export async function voteAndCheck(testEnv: TestEnv, caller: KeyringPair, propos
         account: caller.address,
         proposalId: '0x' + numbersToHex(proposalId as number[]),
         vote: vote,
+        reason: '0x' + numbersToHex(reason),
       },
     },
   ]);
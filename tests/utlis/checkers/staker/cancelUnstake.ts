@@ -0,0 +1,74 @@
+import { KeyringPair } from '@polkadot/keyring/types';
+import { Balance } from 'scripts/types_and_consts';
+import Staker from 'typechain/contracts/staker';
+import { StakeError, Unstake } from 'typechain/types-returns/staker';
+import { expect } from 'tests/setup/chai';
+import { TestEnv } from '../../make-suite';
+import { replaceRNPropsWithStrings } from '../../misc';
+import { BN } from 'bn.js';
+
+export interface CancelUnstakeStorageModifications {
+  //[StakeStorage]
+  totalStake: Balance;
+  stake: Balance;
+  totalUnstake: Balance;
+  unstakes: Unstake[];
+}
+
+export async function getCancelUnstakeStorageModifications(staker: Staker, caller: KeyringPair): Promise<CancelUnstakeStorageModifications> {
+  /* eslint-disable @typescript-eslint/no-non-null-assertion */
+  return {
+    //[StakeStorage]
+    totalStake: (await staker.query.totalStake()).value.ok!.rawNumber,
+    stake: (await staker.query.stakeOf(caller.address)).value.ok!.rawNumber,
+    totalUnstake: (await staker.query.totalUnstake()).value.ok!.rawNumber,
+    unstakes: (await staker.query.initializedUnstakesOf(caller.address)).value.ok!,
+  };
+  /* eslint-enable @typescript-eslint/no-non-null-assertion */
+}
+
+// cancels the unstake at `index` or, if `index` is undefined, restakes all of the `caller` unstakes.
+export async function cancelUnstakeAndCheck(testEnv: TestEnv, caller: KeyringPair, index: number | undefined, error?: StakeError | undefined) {
+  const staker: Staker = testEnv.staker;
+
+  const storageStateBefore: CancelUnstakeStorageModifications = await getCancelUnstakeStorageModifications(staker, caller);
+
+  const queryRes =
+    index !== undefined
+      ? (await staker.withSigner(caller).query.cancelUnstake(index)).value.ok
+      : (await staker.withSigner(caller).query.restakeAllUnstakes()).value.ok;
+  if (error !== undefined) {
+    expect(queryRes).to.have.deep.property('err', error);
+    return;
+  }
+
+  const tx = index !== undefined ? staker.withSigner(caller).tx.cancelUnstake(index) : staker.withSigner(caller).tx.restakeAllUnstakes();
+  await expect(tx).to.eventually.be.fulfilled;
+  const txRes = await tx;
+
+  const storageStateAfter: CancelUnstakeStorageModifications = await getCancelUnstakeStorageModifications(staker, caller);
+
+  const cancelledUnstakes = index !== undefined ? [storageStateBefore.unstakes[index]] : storageStateBefore.unstakes;
+  const expectedUnstakes = index !== undefined ? storageStateBefore.unstakes.filter((_, i) => i !== index) : [];
+  const cancelledAmount = cancelledUnstakes.reduce((acc, unstake) => acc.add(unstake.amount.rawNumber), new BN(0));
+  const storageStateExpected: CancelUnstakeStorageModifications = {
+    //[StakeStorage]
+    totalStake: storageStateBefore.totalStake.add(cancelledAmount),
+    stake: storageStateBefore.stake.add(cancelledAmount),
+    totalUnstake: storageStateBefore.totalUnstake.sub(cancelledAmount),
+    unstakes: expectedUnstakes,
+  };
+
+  expect.soft(replaceRNPropsWithStrings(storageStateAfter)).to.deep.equal(replaceRNPropsWithStrings(storageStateExpected));
+
+  expect.soft(replaceRNPropsWithStrings(txRes.events)).to.deep.equal([
+    {
+      name: 'UnstakeCancelled',
+      args: {
+        caller: caller.address,
+        amount: cancelledAmount.toString(),
+      },
+    },
+  ]);
+  expect.flushSoft();
+}
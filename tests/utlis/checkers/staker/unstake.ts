@@ -46,6 +46,7 @@ export async function unstakeAndCheck(testEnv: TestEnv, caller: KeyringPair, err
   const storageStateAfter: UnstakeStorageModifications = await getUnstakeStorageModifications(testEnv.staker, caller);
 
   const expectedUnstakes = storageStateBefore.unstakes.filter((unstake) => timestamp < unstake.initTime + unstakePeriod);
+  const unstakedUnstakes = storageStateBefore.unstakes.filter((unstake) => timestamp >= unstake.initTime + unstakePeriod);
   const unstakedAmount = storageStateBefore.unstakes.reduce((acc, unstake) => {
     if (timestamp < unstake.initTime + unstakePeriod) {
       return acc;
@@ -66,6 +67,10 @@ export async function unstakeAndCheck(testEnv: TestEnv, caller: KeyringPair, err
       name: 'Unstaked',
       args: {
         caller: caller.address,
+        recipient: caller.address,
+        amount: unstakedAmount.toString(),
+        unstakes: replaceRNPropsWithStrings(unstakedUnstakes),
+        remainingUnstakes: expectedUnstakes.length,
       },
     },
   ]);
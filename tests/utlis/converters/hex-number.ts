@@ -18,3 +18,17 @@ export function numbersToHex(bytes: number[]): string {
 
   return hexString;
 }
+
+export function paramsToInputNumbers(params: Uint8Array) {
+  let ecdStr = '';
+  for (let i = 1; i < params.length; ++i) {
+    let stemp = params[i].toString(16);
+    if (stemp.length < 2) {
+      stemp = '0' + stemp;
+    }
+    ecdStr += stemp;
+  }
+  const selector = hexToNumbers(ecdStr.substring(0, 8));
+  const data = hexToNumbers(ecdStr.substring(8));
+  return { selector, data };
+}
@@ -11,6 +11,7 @@ import Hasher from 'typechain/contracts/hasher';
 import GovernanceToken from 'typechain/contracts/governance_token';
 import GovernanceTokenMinter from 'typechain/contracts/governance_token_minter';
 import BlockTimestampProvider from 'typechain/contracts/block_timestamp_provider';
+import Treasury from 'typechain/contracts/treasury';
 
 export interface TestEnv {
   users: KeyringPair[];
@@ -22,6 +23,7 @@ export interface TestEnv {
   timestampProvider: BlockTimestampProvider;
   hasher: Hasher;
   governanceTokenMinter: GovernanceTokenMinter;
+  treasury: Treasury;
 }
 
 function makeSuiteInternal(
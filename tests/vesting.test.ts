@@ -0,0 +1,148 @@
+import { KeyringPair } from '@polkadot/keyring/types';
+import BN from 'bn.js';
+import { DAY, E12, E21, E6 } from 'scripts/types_and_consts';
+import BlockTimestsampProvider from 'typechain/contracts/block_timestamp_provider';
+import GovernanceToken from 'typechain/contracts/governance_token';
+import Governor from 'typechain/contracts/governor';
+import { Proposal, Transaction, Vote, VestingKind } from 'typechain/types-arguments/governor';
+import { GovernErrorBuilder, OwnableErrorBuilder, ProposalStatus, VestingErrorBuilder } from 'typechain/types-returns/governor';
+import { hexToNumbers, paramsToInputNumbers } from './utlis/converters/hex-number';
+import { TestEnv, makeSuite } from './utlis/make-suite';
+import { replaceRNPropsWithStrings } from './utlis/misc';
+import { expect } from './setup/chai';
+
+makeSuite('Vesting tests', (getTestEnv) => {
+  let testEnv: TestEnv;
+  let deployer: KeyringPair;
+  let users: KeyringPair[];
+  let governor: Governor;
+  let govToken: GovernanceToken;
+  let timestmpProvider: BlockTimestsampProvider;
+
+  const bigStake = E6.mul(E12).muln(10);
+  const vestingDuration = 10 * DAY;
+
+  const vestingTransaction = (message: string, args: unknown[]): Transaction => {
+    const params = paramsToInputNumbers(governor.abi.findMessage(message).toU8a(args));
+    return {
+      callee: governor.address,
+      selector: params.selector,
+      input: params.data,
+      transferredValue: 0,
+      gasLimit: null,
+      allowFailure: false,
+    };
+  };
+
+  const propose = async (proposer: KeyringPair, transactions: Transaction[], description: string) => {
+    const proposal: Proposal = {
+      rulesId: 0,
+      voterRewardPartE12: 0,
+      transactions,
+    };
+    const proposalId = hexToNumbers(((await testEnv.hasher.query.hashProposalWithDescription(proposal, description)).value.ok! as string).substring(2));
+    await governor.withSigner(proposer).tx.propose(proposal, description);
+    return proposalId;
+  };
+
+  const passAndQueue = async (transactions: Transaction[], description: string) => {
+    const proposalId = await propose(users[1], transactions, description);
+    await governor.withSigner(users[0]).tx.vote(proposalId, Vote.agreed, []);
+    await governor.withSigner(users[1]).tx.vote(proposalId, Vote.agreed, []);
+    await timestmpProvider.tx.increaseBlockTimestamp(9 * DAY);
+    await governor.tx.finalize(proposalId);
+    await governor.tx.queue(proposalId);
+    await timestmpProvider.tx.increaseBlockTimestamp(2 * DAY);
+    return proposalId;
+  };
+
+  beforeEach(async () => {
+    testEnv = getTestEnv();
+    deployer = testEnv.deployer;
+    users = testEnv.users;
+    governor = testEnv.governor;
+    govToken = testEnv.govToken;
+    timestmpProvider = testEnv.timestampProvider;
+
+    await govToken.withSigner(deployer).tx.transfer(users[0].address, bigStake, []);
+    await govToken.withSigner(deployer).tx.transfer(users[1].address, bigStake, []);
+
+    await govToken.withSigner(users[0]).tx.increaseAllowance(governor.address, E21);
+    await govToken.withSigner(users[1]).tx.increaseAllowance(governor.address, E21);
+
+    await governor.withSigner(users[0]).tx.stake(bigStake);
+    await governor.withSigner(users[1]).tx.stake(bigStake);
+    await timestmpProvider.tx.increaseBlockTimestamp(1);
+  });
+
+  it('user0 who is not the owner tries to create vesting', async () => {
+    const queryRes = (await governor.withSigner(users[0]).query.createVesting(users[0].address, 1, 0, DAY, VestingKind.linear)).value.ok;
+    expect(queryRes).to.have.deep.property('err', VestingErrorBuilder.OwnableError(OwnableErrorBuilder.CallerIsNotOwner()));
+  });
+  it('user0 tries to release vesting that doesnt exist', async () => {
+    const queryRes = (await governor.withSigner(users[0]).query.release(0)).value.ok;
+    expect(queryRes).to.have.deep.property('err', VestingErrorBuilder.NoSuchVesting());
+  });
+  it('proposal creating vesting that is not funded by the treasury fails to execute', async () => {
+    const start = (await governor.query.timestamp()).value.ok! + 11 * DAY;
+    const proposalId = await passAndQueue(
+      [vestingTransaction('Vesting::create_vesting', [users[2].address, E12.toString(), start, vestingDuration, 'Linear'])],
+      'unfunded vesting',
+    );
+    const queryRes = (await governor.withSigner(users[0]).query.executeById(proposalId)).value.ok;
+    expect(queryRes).to.have.deep.property('err', GovernErrorBuilder.UnderlyingTransactionReverted(0));
+  });
+  describe('treasury is funded by slashing the proposer and governance vests it to user2', () => {
+    let treasuryWant: BN;
+    beforeEach(async () => {
+      const slashedProposalId = await propose(users[0], [], 'proposal to be slashed');
+      await governor.withSigner(users[0]).tx.vote(slashedProposalId, Vote.disagreedWithProposerSlashing, []);
+      await governor.withSigner(users[1]).tx.vote(slashedProposalId, Vote.disagreedWithProposerSlashing, []);
+      await governor.tx.finalize(slashedProposalId);
+      expect((await governor.query.status(slashedProposalId)).value.ok).to.be.equal(ProposalStatus.defeatedWithSlash);
+      treasuryWant = (await governor.query.treasuryBalance()).value.ok!.want.rawNumber;
+    });
+    it('user2 releases half of linear vesting after half of duration and the rest after the end', async () => {
+      const start = (await governor.query.timestamp()).value.ok! + 11 * DAY;
+      const proposalId = await passAndQueue(
+        [vestingTransaction('Vesting::create_vesting', [users[2].address, treasuryWant.toString(), start, vestingDuration, 'Linear'])],
+        'linear vesting',
+      );
+      const txRes = await governor.withSigner(users[0]).tx.executeById(proposalId);
+      const withdrawnEvent = replaceRNPropsWithStrings(txRes.events?.find((event) => event.name === 'TreasuryWithdrawn'));
+      expect(withdrawnEvent?.args.to).to.be.equal(governor.address);
+      expect(withdrawnEvent?.args.amount).to.be.equal(treasuryWant.toString());
+      expect((await governor.query.treasuryBalance()).value.ok!.want.rawNumber.toString()).to.be.equal('0');
+      expect((await governor.query.totalVesting()).value.ok!.rawNumber.toString()).to.be.equal(treasuryWant.toString());
+
+      await timestmpProvider.tx.increaseBlockTimestamp(vestingDuration / 2);
+      const queryRes = (await governor.withSigner(users[3]).query.release(0)).value.ok;
+      expect(queryRes).to.have.deep.property('err', VestingErrorBuilder.CallerIsNotBeneficiary());
+      await governor.withSigner(users[2]).tx.release(0);
+      expect((await govToken.query.balanceOf(users[2].address)).value.ok!.rawNumber.toString()).to.be.equal(treasuryWant.divn(2).toString());
+
+      await timestmpProvider.tx.increaseBlockTimestamp(vestingDuration);
+      await governor.withSigner(users[2]).tx.release(0);
+      expect((await govToken.query.balanceOf(users[2].address)).value.ok!.rawNumber.toString()).to.be.equal(treasuryWant.toString());
+      expect((await governor.query.totalVesting()).value.ok!.rawNumber.toString()).to.be.equal('0');
+    });
+    it('cliff vesting is not releasable before the end and revoking it refunds the treasury', async () => {
+      const cliffDuration = 30 * DAY;
+      const start = (await governor.query.timestamp()).value.ok! + 11 * DAY;
+      const proposalId = await passAndQueue(
+        [vestingTransaction('Vesting::create_vesting', [users[2].address, treasuryWant.toString(), start, cliffDuration, 'Cliff'])],
+        'cliff vesting',
+      );
+      await governor.withSigner(users[0]).tx.executeById(proposalId);
+
+      await timestmpProvider.tx.increaseBlockTimestamp(cliffDuration / 2);
+      const queryRes = (await governor.withSigner(users[2]).query.release(0)).value.ok;
+      expect(queryRes).to.have.deep.property('err', VestingErrorBuilder.NothingToRelease());
+
+      const revokeProposalId = await passAndQueue([vestingTransaction('Vesting::revoke', [0])], 'revoke vesting');
+      await governor.withSigner(users[0]).tx.executeById(revokeProposalId);
+      expect((await governor.query.vesting(0)).value.ok).to.be.null;
+      expect((await governor.query.treasuryBalance()).value.ok!.want.rawNumber.toString()).to.be.equal(treasuryWant.toString());
+    });
+  });
+});